@@ -0,0 +1,64 @@
+//! Color primitives and transforms.
+
+mod transforms;
+
+use crate::prelude::*;
+
+pub use transforms::{
+    Darken,
+    Lighten,
+    Opacity,
+};
+
+/// A color described by its red, green, blue and alpha channels.
+///
+/// Every channel is kept in the `[0.0, 1.0]` range.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::color::Color;
+///
+/// fn main() {
+///     let color = Color::new(0.5, 1.5, -0.5, 1.0);
+///     println!("{color:?}");
+/// # assert_eq!(color, Color::new(0.5, 1.0, 0.0, 1.0));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Color {
+    r: f64,
+    g: f64,
+    b: f64,
+    a: f64,
+}
+
+impl Color {
+    /// Creates a new color, clamping its channels to the `[0.0, 1.0]` range.
+    pub fn new(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self {
+            r: r.clamp(0.0, 1.0),
+            g: g.clamp(0.0, 1.0),
+            b: b.clamp(0.0, 1.0),
+            a: a.clamp(0.0, 1.0),
+        }
+    }
+
+    /// The red channel.
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    /// The green channel.
+    pub fn g(&self) -> f64 {
+        self.g
+    }
+
+    /// The blue channel.
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// The alpha channel.
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
@@ -0,0 +1,169 @@
+//! [`color`](crate::color) module transforms.
+
+use super::*;
+use crate::transform::{
+    InversibleTransform,
+    MutableTransform,
+    OnceTransform,
+    Transform,
+};
+
+/// A transform lightening a color by adding an amount to its red, green and blue channels.
+///
+/// The inverse of a lighten is a [`Darken`] by the same amount. Since channels are clamped, the
+/// round trip only holds when no channel saturates.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     color::*,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     let color = Color::new(0.2, 0.4, 0.6, 1.0);
+///     let lighten = Lighten(0.25);
+///     let lightened = lighten.apply(color);
+///     let restored = lighten.inverse().apply(lightened);
+///     println!("{lightened:?} {restored:?}");
+/// # let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+/// # assert!(close(lightened.r(), 0.45));
+/// # assert!(close(restored.r(), color.r()));
+/// # assert!(close(restored.g(), color.g()));
+/// # assert!(close(restored.b(), color.b()));
+/// # assert_eq!(restored.a(), color.a());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Lighten(pub f64);
+
+impl OnceTransform<Color> for Lighten {
+    type Output = Color;
+
+    fn apply_once(self, input: Color) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Color> for Lighten {
+    fn apply_mut(&mut self, input: Color) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Color> for Lighten {
+    fn apply(&self, input: Color) -> Self::Output {
+        let Self(amount) = self;
+        Color::new(
+            input.r() + amount,
+            input.g() + amount,
+            input.b() + amount,
+            input.a(),
+        )
+    }
+}
+
+impl InversibleTransform<Color> for Lighten {
+    type Inverse = Darken;
+
+    fn inverse(&self) -> Self::Inverse {
+        let Self(amount) = self;
+        Darken(*amount)
+    }
+}
+
+/// A transform darkening a color by subtracting an amount from its red, green and blue channels.
+///
+/// The inverse of a darken is a [`Lighten`] by the same amount. Since channels are clamped, the
+/// round trip only holds when no channel saturates.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     color::*,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     let color = Color::new(0.2, 0.4, 0.6, 1.0);
+///     let darkened = Darken(0.5).apply(color);
+///     println!("{darkened:?}");
+/// # let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+/// # assert_eq!(darkened.r(), 0.0);
+/// # assert!(close(darkened.b(), 0.1));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Darken(pub f64);
+
+impl OnceTransform<Color> for Darken {
+    type Output = Color;
+
+    fn apply_once(self, input: Color) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Color> for Darken {
+    fn apply_mut(&mut self, input: Color) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Color> for Darken {
+    fn apply(&self, input: Color) -> Self::Output {
+        let Self(amount) = self;
+        Color::new(
+            input.r() - amount,
+            input.g() - amount,
+            input.b() - amount,
+            input.a(),
+        )
+    }
+}
+
+impl InversibleTransform<Color> for Darken {
+    type Inverse = Lighten;
+
+    fn inverse(&self) -> Self::Inverse {
+        let Self(amount) = self;
+        Lighten(*amount)
+    }
+}
+
+/// A transform scaling the alpha channel of a color.
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     color::*,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     let faded = Opacity(0.5).apply(Color::new(1.0, 1.0, 1.0, 0.8));
+///     println!("{faded:?}");
+/// # assert_eq!(faded, Color::new(1.0, 1.0, 1.0, 0.4));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Opacity(pub f64);
+
+impl OnceTransform<Color> for Opacity {
+    type Output = Color;
+
+    fn apply_once(self, input: Color) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Color> for Opacity {
+    fn apply_mut(&mut self, input: Color) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Color> for Opacity {
+    fn apply(&self, input: Color) -> Self::Output {
+        let Self(opacity) = self;
+        Color::new(input.r(), input.g(), input.b(), input.a() * opacity)
+    }
+}
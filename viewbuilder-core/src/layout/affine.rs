@@ -0,0 +1,181 @@
+//! [`AffineTransform`](crate::layout::AffineTransform) definition.
+
+use super::*;
+use crate::transform::{
+    InversibleTransform,
+    MutableTransform,
+    OnceTransform,
+    Transform,
+};
+
+/// A two dimensional affine transform, combining a linear map and a translation.
+///
+/// The transform is the matrix
+/// ```text
+/// | a  c  tx |
+/// | b  d  ty |
+/// ```
+/// mapping a position `(x, y)` to `(a * x + c * y + tx, b * x + d * y + ty)`.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     let translate = AffineTransform::translation(10.0, 20.0);
+///     let scale = AffineTransform::scale(2.0, 3.0);
+///     let combined = translate.concat(&scale);
+///
+///     let position = Position::new(1.0, 1.0);
+///     let sequential = scale.apply(translate.apply(position));
+///     assert_eq!(combined.apply(position), sequential);
+///     assert_eq!(sequential, Position::new(22.0, 63.0));
+///
+///     let restored = combined.inverse().apply(sequential);
+///     assert!((restored.x() - 1.0).abs() < 1e-12 && (restored.y() - 1.0).abs() < 1e-12);
+///     assert_eq!(AffineTransform::IDENTITY.apply(position), position);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineTransform {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    tx: f64,
+    ty: f64,
+}
+
+impl AffineTransform {
+    /// The transform leaving every position unchanged.
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    /// Creates a transform from its matrix coefficients.
+    pub const fn new(a: f64, b: f64, c: f64, d: f64, tx: f64, ty: f64) -> Self {
+        Self { a, b, c, d, tx, ty }
+    }
+
+    /// Creates a transform translating positions by `tx` horizontally and `ty` vertically.
+    pub const fn translation(tx: f64, ty: f64) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    /// Creates a transform scaling positions by `sx` horizontally and `sy` vertically about the
+    /// origin.
+    pub const fn scale(sx: f64, sy: f64) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Creates a transform rotating positions by an `angle` about the origin, in the same
+    /// direction as [`Rotate`].
+    pub fn rotation(angle: Angle) -> Self {
+        let (sin, cos) = angle.radians().sin_cos();
+        Self::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// The matrix coefficients, as `[a, b, c, d, tx, ty]`.
+    pub fn coefficients(&self) -> [f64; 6] {
+        let Self { a, b, c, d, tx, ty } = *self;
+        [a, b, c, d, tx, ty]
+    }
+
+    /// Composes the transform with `next`, yielding the transform applying this one then `next`.
+    pub fn concat(&self, next: &AffineTransform) -> Self {
+        Self::new(
+            next.a * self.a + next.c * self.b,
+            next.b * self.a + next.d * self.b,
+            next.a * self.c + next.c * self.d,
+            next.b * self.c + next.d * self.d,
+            next.a * self.tx + next.c * self.ty + next.tx,
+            next.b * self.tx + next.d * self.ty + next.ty,
+        )
+    }
+
+    /// The determinant of the linear part of the transform.
+    ///
+    /// A zero determinant means the transform collapses the plane onto a line or a point, so it
+    /// cannot be inverted.
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// The transform undoing this one, `None` when the determinant is zero.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::AffineTransform;
+    ///
+    /// fn main() {
+    ///     let flatten = AffineTransform::scale(1.0, 0.0);
+    ///     assert_eq!(flatten.determinant(), 0.0);
+    ///     assert_eq!(flatten.invert(), None);
+    ///
+    ///     let translate = AffineTransform::translation(1.0, 2.0);
+    ///     assert_eq!(
+    ///         translate.invert(),
+    ///         Some(AffineTransform::translation(-1.0, -2.0))
+    ///     );
+    /// }
+    /// ```
+    pub fn invert(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant == 0.0 {
+            return None;
+        }
+
+        let Self { a, b, c, d, tx, ty } = *self;
+        Some(Self::new(
+            d / determinant,
+            -b / determinant,
+            -c / determinant,
+            a / determinant,
+            (c * ty - d * tx) / determinant,
+            (b * tx - a * ty) / determinant,
+        ))
+    }
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl OnceTransform<Position> for AffineTransform {
+    type Output = Position;
+
+    fn apply_once(self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Position> for AffineTransform {
+    fn apply_mut(&mut self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Position> for AffineTransform {
+    fn apply(&self, input: Position) -> Self::Output {
+        let Self { a, b, c, d, tx, ty } = *self;
+        Position::new(
+            a * input.x() + c * input.y() + tx,
+            b * input.x() + d * input.y() + ty,
+        )
+    }
+}
+
+impl InversibleTransform<Position> for AffineTransform {
+    type Inverse = AffineTransform;
+
+    /// The transform undoing this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the determinant is zero, see [`AffineTransform::invert`] for a checked version.
+    fn inverse(&self) -> Self::Inverse {
+        self.invert()
+            .expect("affine transform with a zero determinant is not invertible")
+    }
+}
@@ -0,0 +1,145 @@
+//! [`Alignment`](crate::layout::Alignment) definitions.
+
+use super::*;
+
+/// An alignment along the horizontal axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HorizontalAlignment {
+    /// Aligns with the leading edge.
+    Leading,
+    /// Aligns with the horizontal center.
+    #[default]
+    Center,
+    /// Aligns with the trailing edge.
+    Trailing,
+}
+
+impl HorizontalAlignment {
+    /// The physical edge the alignment designates under a layout `direction`, `None` for the
+    /// center.
+    pub fn resolve(&self, direction: LayoutDirection) -> Option<HorizontalEdge> {
+        match self {
+            Self::Leading => Some(direction.leading_edge()),
+            Self::Center => None,
+            Self::Trailing => Some(direction.trailing_edge()),
+        }
+    }
+
+    /// The position of the alignment as a fraction of the width, from the leading edge.
+    pub(crate) fn fraction(&self) -> f64 {
+        match self {
+            Self::Leading => 0.0,
+            Self::Center => 0.5,
+            Self::Trailing => 1.0,
+        }
+    }
+}
+
+/// An alignment along the vertical axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VerticalAlignment {
+    /// Aligns with the top edge.
+    Top,
+    /// Aligns with the vertical center.
+    #[default]
+    Center,
+    /// Aligns with the bottom edge.
+    Bottom,
+}
+
+impl VerticalAlignment {
+    /// The position of the alignment as a fraction of the height, from the top edge.
+    pub(crate) fn fraction(&self) -> f64 {
+        match self {
+            Self::Top => 0.0,
+            Self::Center => 0.5,
+            Self::Bottom => 1.0,
+        }
+    }
+}
+
+/// An alignment along both axes.
+///
+/// Coordinates grow towards the bottom trailing corner, so the top leading corner of a rectangle
+/// is its origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Alignment {
+    horizontal: HorizontalAlignment,
+    vertical: VerticalAlignment,
+}
+
+impl Alignment {
+    /// Aligns with the top leading corner.
+    pub const TOP_LEADING: Self = Self::new(HorizontalAlignment::Leading, VerticalAlignment::Top);
+    /// Aligns with the center of the top edge.
+    pub const TOP: Self = Self::new(HorizontalAlignment::Center, VerticalAlignment::Top);
+    /// Aligns with the top trailing corner.
+    pub const TOP_TRAILING: Self = Self::new(HorizontalAlignment::Trailing, VerticalAlignment::Top);
+    /// Aligns with the center of the leading edge.
+    pub const LEADING: Self = Self::new(HorizontalAlignment::Leading, VerticalAlignment::Center);
+    /// Aligns with the center.
+    pub const CENTER: Self = Self::new(HorizontalAlignment::Center, VerticalAlignment::Center);
+    /// Aligns with the center of the trailing edge.
+    pub const TRAILING: Self = Self::new(HorizontalAlignment::Trailing, VerticalAlignment::Center);
+    /// Aligns with the bottom leading corner.
+    pub const BOTTOM_LEADING: Self =
+        Self::new(HorizontalAlignment::Leading, VerticalAlignment::Bottom);
+    /// Aligns with the center of the bottom edge.
+    pub const BOTTOM: Self = Self::new(HorizontalAlignment::Center, VerticalAlignment::Bottom);
+    /// Aligns with the bottom trailing corner.
+    pub const BOTTOM_TRAILING: Self =
+        Self::new(HorizontalAlignment::Trailing, VerticalAlignment::Bottom);
+
+    /// Creates an alignment from its components.
+    pub const fn new(horizontal: HorizontalAlignment, vertical: VerticalAlignment) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+
+    /// The alignment along the horizontal axis.
+    pub fn horizontal(&self) -> HorizontalAlignment {
+        self.horizontal
+    }
+
+    /// The alignment along the vertical axis.
+    pub fn vertical(&self) -> VerticalAlignment {
+        self.vertical
+    }
+
+    /// The alignment designating the same point under a layout `direction` as this one does from
+    /// left to right.
+    ///
+    /// Coordinates grow towards the right edge whatever the direction, so resolving swaps the
+    /// leading and trailing alignments from right to left.
+    pub fn resolve(&self, direction: LayoutDirection) -> Self {
+        let horizontal = match self.horizontal.resolve(direction) {
+            Some(HorizontalEdge::Left) => HorizontalAlignment::Leading,
+            Some(HorizontalEdge::Right) => HorizontalAlignment::Trailing,
+            None => HorizontalAlignment::Center,
+        };
+        Self::new(horizontal, self.vertical)
+    }
+}
+
+/// An alignment guide along either axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Guide {
+    /// A guide positioned along the horizontal axis.
+    Horizontal(HorizontalAlignment),
+    /// A guide positioned along the vertical axis.
+    Vertical(VerticalAlignment),
+}
+
+impl From<HorizontalAlignment> for Guide {
+    fn from(alignment: HorizontalAlignment) -> Self {
+        Self::Horizontal(alignment)
+    }
+}
+
+impl From<VerticalAlignment> for Guide {
+    fn from(alignment: VerticalAlignment) -> Self {
+        Self::Vertical(alignment)
+    }
+}
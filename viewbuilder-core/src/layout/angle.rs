@@ -0,0 +1,89 @@
+//! [`Angle`](crate::layout::Angle) definition.
+
+use super::*;
+use std::ops::{
+    Add,
+    Mul,
+    Neg,
+    Sub,
+};
+
+/// A planar angle.
+///
+/// Angles are stored in radians, constructing and reading them always names the unit so degrees
+/// and radians cannot be mixed up.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::f64::consts::PI;
+/// use viewbuilder::layout::Angle;
+///
+/// fn main() {
+///     let half_turn = Angle::from_degrees(180.0);
+///     assert!((half_turn.radians() - PI).abs() < 1e-12);
+///
+///     let quarter_turn = Angle::from_radians(PI / 2.0);
+///     assert!(((half_turn - quarter_turn).degrees() - 90.0).abs() < 1e-12);
+///     assert!(((quarter_turn * 3.0).degrees() - 270.0).abs() < 1e-12);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Angle {
+    radians: f64,
+}
+
+impl Angle {
+    /// The null angle.
+    pub const ZERO: Self = Self::from_radians(0.0);
+
+    /// Creates an angle measured in radians.
+    pub const fn from_radians(radians: f64) -> Self {
+        Self { radians }
+    }
+
+    /// Creates an angle measured in degrees.
+    pub fn from_degrees(degrees: f64) -> Self {
+        Self::from_radians(degrees.to_radians())
+    }
+
+    /// The measure of the angle in radians.
+    pub fn radians(&self) -> f64 {
+        self.radians
+    }
+
+    /// The measure of the angle in degrees.
+    pub fn degrees(&self) -> f64 {
+        self.radians.to_degrees()
+    }
+}
+
+impl Add for Angle {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_radians(self.radians + rhs.radians)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_radians(self.radians - rhs.radians)
+    }
+}
+
+impl Mul<f64> for Angle {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_radians(self.radians * rhs)
+    }
+}
+
+impl Neg for Angle {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_radians(-self.radians)
+    }
+}
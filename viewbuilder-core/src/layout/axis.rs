@@ -0,0 +1,58 @@
+//! [`Axis`](crate::layout::Axis) definition.
+
+use super::*;
+
+/// One of the two axes of a layout.
+///
+/// Geometry types expose axis parameterized accessors, `main` reading the component along the
+/// axis and `cross` the one along the perpendicular axis, so layout code can be written once for
+/// both orientations.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::*;
+///
+/// fn main() {
+///     let size = Size::new(4.0, 3.0);
+///     assert_eq!(size.main(Axis::Horizontal), size.width());
+///     assert_eq!(size.cross(Axis::Horizontal), size.height());
+///     assert_eq!(size.main(Axis::Vertical), size.height());
+///     assert_eq!(size.cross(Axis::Vertical), size.width());
+///
+///     let position = Position::new(1.0, 2.0);
+///     assert_eq!(position.main(Axis::Vertical), position.y());
+///     assert_eq!(Axis::Horizontal.perpendicular(), Axis::Vertical);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// The axis along which `x` coordinates and widths are measured.
+    Horizontal,
+    /// The axis along which `y` coordinates and heights are measured.
+    Vertical,
+}
+
+impl Axis {
+    /// The other axis.
+    pub fn perpendicular(self) -> Self {
+        match self {
+            Self::Horizontal => Self::Vertical,
+            Self::Vertical => Self::Horizontal,
+        }
+    }
+
+    /// The lane of the axis in two dimensional vectors.
+    pub(crate) fn index(self) -> usize {
+        match self {
+            Self::Horizontal => 0,
+            Self::Vertical => 1,
+        }
+    }
+
+    /// The `[x, y]` components of the `main` and `cross` components along the axis.
+    pub(crate) fn components(self, main: f64, cross: f64) -> [f64; 2] {
+        match self {
+            Self::Horizontal => [main, cross],
+            Self::Vertical => [cross, main],
+        }
+    }
+}
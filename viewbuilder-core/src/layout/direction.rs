@@ -0,0 +1,85 @@
+//! [`LayoutDirection`](crate::layout::LayoutDirection) definition.
+
+use super::*;
+use crate::view::environment::EnvironmentKey;
+
+/// A physical horizontal edge, independent of the layout direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HorizontalEdge {
+    /// The edge with the smallest horizontal coordinate.
+    Left,
+    /// The edge with the largest horizontal coordinate.
+    Right,
+}
+
+/// The direction in which horizontal content flows.
+///
+/// The leading and trailing edges follow the direction: leading is the left edge from left to
+/// right and the right edge from right to left. The direction is an environment value, injected
+/// with the [`environment`](crate::view::ViewExt::environment) modifier.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     prelude::*,
+///     view::environment::*,
+/// };
+///
+/// fn main() {
+///     let leading = HorizontalAlignment::Leading;
+///     assert_eq!(
+///         leading.resolve(LayoutDirection::LeftToRight),
+///         Some(HorizontalEdge::Left)
+///     );
+///     assert_eq!(
+///         leading.resolve(LayoutDirection::RightToLeft),
+///         Some(HorizontalEdge::Right)
+///     );
+///     assert_eq!(
+///         HorizontalAlignment::Center.resolve(LayoutDirection::RightToLeft),
+///         None
+///     );
+///
+///     let view = ().environment::<LayoutDirection>(LayoutDirection::RightToLeft);
+///     let environment = view.resolve_environment(&EnvironmentValues::new());
+///     let direction = environment.get::<LayoutDirection>();
+///     let insets = EdgeInsets::new(0.0, 16.0, 0.0, 4.0).resolve(direction);
+///     assert_eq!((insets.left(), insets.right()), (4.0, 16.0));
+///
+///     let rect = Rect::new(Position::zero(), Size::new(100.0, 50.0));
+///     let anchor = rect.anchor_point(Alignment::LEADING.resolve(direction));
+///     assert_eq!(anchor, Position::new(100.0, 25.0));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LayoutDirection {
+    /// Content flows from the left edge to the right edge.
+    #[default]
+    LeftToRight,
+    /// Content flows from the right edge to the left edge.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// The physical edge content starts from.
+    pub fn leading_edge(&self) -> HorizontalEdge {
+        match self {
+            Self::LeftToRight => HorizontalEdge::Left,
+            Self::RightToLeft => HorizontalEdge::Right,
+        }
+    }
+
+    /// The physical edge content flows towards.
+    pub fn trailing_edge(&self) -> HorizontalEdge {
+        match self {
+            Self::LeftToRight => HorizontalEdge::Right,
+            Self::RightToLeft => HorizontalEdge::Left,
+        }
+    }
+}
+
+impl EnvironmentKey for LayoutDirection {
+    type Value = Self;
+
+    const DEFAULT: Self::Value = Self::LeftToRight;
+}
@@ -0,0 +1,150 @@
+//! [`EdgeInsets`](crate::layout::EdgeInsets) definition.
+
+use super::*;
+use std::ops::Add;
+
+/// The inset distances of each edge of a rectangle.
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::EdgeInsets;
+///
+/// fn main() {
+///     let insets = EdgeInsets::new(20.0, 0.0, 34.0, 0.0) + EdgeInsets::all(8.0);
+///     println!("{insets:?}");
+/// # assert_eq!(insets, EdgeInsets::new(28.0, 8.0, 42.0, 8.0));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EdgeInsets {
+    top: f64,
+    leading: f64,
+    bottom: f64,
+    trailing: f64,
+}
+
+impl EdgeInsets {
+    /// The insets of zero length on every edge.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Creates insets from the distance of each edge.
+    pub const fn new(top: f64, leading: f64, bottom: f64, trailing: f64) -> Self {
+        Self {
+            top,
+            leading,
+            bottom,
+            trailing,
+        }
+    }
+
+    /// Creates insets of the same `length` on every edge.
+    pub const fn all(length: f64) -> Self {
+        Self::new(length, length, length, length)
+    }
+
+    /// The inset of the top edge.
+    pub fn top(&self) -> f64 {
+        self.top
+    }
+
+    /// The inset of the leading edge.
+    pub fn leading(&self) -> f64 {
+        self.leading
+    }
+
+    /// The inset of the bottom edge.
+    pub fn bottom(&self) -> f64 {
+        self.bottom
+    }
+
+    /// The inset of the trailing edge.
+    pub fn trailing(&self) -> f64 {
+        self.trailing
+    }
+
+    /// The physical insets under a layout `direction`.
+    ///
+    /// The leading inset is on the left edge from left to right and on the right edge from right
+    /// to left.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::*;
+    ///
+    /// fn main() {
+    ///     let insets = EdgeInsets::new(0.0, 16.0, 0.0, 4.0);
+    ///     let ltr = insets.resolve(LayoutDirection::LeftToRight);
+    ///     assert_eq!((ltr.left(), ltr.right()), (16.0, 4.0));
+    ///     let rtl = insets.resolve(LayoutDirection::RightToLeft);
+    ///     assert_eq!((rtl.left(), rtl.right()), (4.0, 16.0));
+    /// }
+    /// ```
+    pub fn resolve(&self, direction: LayoutDirection) -> PhysicalEdgeInsets {
+        match direction {
+            LayoutDirection::LeftToRight => {
+                PhysicalEdgeInsets::new(self.top, self.leading, self.bottom, self.trailing)
+            }
+            LayoutDirection::RightToLeft => {
+                PhysicalEdgeInsets::new(self.top, self.trailing, self.bottom, self.leading)
+            }
+        }
+    }
+}
+
+impl Add for EdgeInsets {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.top + rhs.top,
+            self.leading + rhs.leading,
+            self.bottom + rhs.bottom,
+            self.trailing + rhs.trailing,
+        )
+    }
+}
+
+/// The inset distances of each physical edge of a rectangle.
+///
+/// Obtained by resolving [`EdgeInsets`] under a layout direction with [`EdgeInsets::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PhysicalEdgeInsets {
+    top: f64,
+    left: f64,
+    bottom: f64,
+    right: f64,
+}
+
+impl PhysicalEdgeInsets {
+    /// The insets of zero length on every edge.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Creates insets from the distance of each edge.
+    pub const fn new(top: f64, left: f64, bottom: f64, right: f64) -> Self {
+        Self {
+            top,
+            left,
+            bottom,
+            right,
+        }
+    }
+
+    /// The inset of the top edge.
+    pub fn top(&self) -> f64 {
+        self.top
+    }
+
+    /// The inset of the left edge.
+    pub fn left(&self) -> f64 {
+        self.left
+    }
+
+    /// The inset of the bottom edge.
+    pub fn bottom(&self) -> f64 {
+        self.bottom
+    }
+
+    /// The inset of the right edge.
+    pub fn right(&self) -> f64 {
+        self.right
+    }
+}
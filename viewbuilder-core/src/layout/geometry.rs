@@ -0,0 +1,280 @@
+//! Computational geometry over [`Position`](crate::layout::Position) sets.
+
+use super::*;
+
+/// The z component of the cross product of `origin -> a` and `origin -> b`.
+///
+/// Positive when `origin`, `a` and `b` make a counterclockwise turn, negative for a clockwise turn
+/// and zero when they are collinear.
+fn cross(origin: Position, a: Position, b: Position) -> f64 {
+    (a.x() - origin.x()) * (b.y() - origin.y()) - (a.y() - origin.y()) * (b.x() - origin.x())
+}
+
+/// Computes the convex hull of a set of positions.
+///
+/// Uses the monotone chain algorithm and returns the hull vertices in counterclockwise order,
+/// starting from the leftmost (then lowest) position. Duplicate and collinear positions are not
+/// part of the hull, so fewer than three distinct positions are returned as is and an all-collinear
+/// set is reduced to its two endpoints.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::{
+///     geometry::convex_hull,
+///     Position,
+/// };
+///
+/// fn main() {
+///     let points = [
+///         Position::new(0.0, 0.0),
+///         Position::new(1.0, 1.0),
+///         Position::new(0.5, 0.5),
+///         Position::new(1.0, 0.0),
+///         Position::new(0.0, 1.0),
+///     ];
+///     let hull = convex_hull(&points);
+///     assert_eq!(
+///         hull,
+///         vec![
+///             Position::new(0.0, 0.0),
+///             Position::new(1.0, 0.0),
+///             Position::new(1.0, 1.0),
+///             Position::new(0.0, 1.0),
+///         ]
+///     );
+///
+///     assert!(convex_hull(&[]).is_empty());
+///     assert_eq!(convex_hull(&[Position::zero()]), vec![Position::zero()]);
+///     let line = [
+///         Position::new(2.0, 2.0),
+///         Position::new(0.0, 0.0),
+///         Position::new(1.0, 1.0),
+///     ];
+///     assert_eq!(
+///         convex_hull(&line),
+///         vec![Position::new(0.0, 0.0), Position::new(2.0, 2.0)]
+///     );
+/// }
+/// ```
+pub fn convex_hull(points: &[Position]) -> Vec<Position> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x().total_cmp(&b.x()).then(a.y().total_cmp(&b.y())));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<Position> = Vec::with_capacity(sorted.len() + 1);
+    for &point in &sorted {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    let lower_len = hull.len() + 1;
+    for &point in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // The last point closes the chain back onto the first one.
+    hull.pop();
+    hull
+}
+
+/// Below this number of positions the closest pair is searched by brute force.
+const CLOSEST_PAIR_BRUTE_FORCE_LEN: usize = 8;
+
+/// The euclidean distance between `a` and `b`.
+fn distance(a: Position, b: Position) -> f64 {
+    (b.x() - a.x()).hypot(b.y() - a.y())
+}
+
+/// The closest pair of `points` designated by `indices`, comparing every pair.
+fn closest_pair_brute_force(points: &[Position], indices: &[usize]) -> Option<(usize, usize, f64)> {
+    let mut closest: Option<(usize, usize, f64)> = None;
+    for (offset, &a) in indices.iter().enumerate() {
+        for &b in &indices[offset + 1..] {
+            let distance = distance(points[a], points[b]);
+            if closest.is_none_or(|(_, _, closest)| distance < closest) {
+                closest = Some((a, b, distance));
+            }
+        }
+    }
+    closest
+}
+
+/// The closest pair of `points` designated by `by_x`, sorted by horizontal coordinate.
+fn closest_pair_recursive(points: &[Position], by_x: &[usize]) -> Option<(usize, usize, f64)> {
+    if by_x.len() <= CLOSEST_PAIR_BRUTE_FORCE_LEN {
+        return closest_pair_brute_force(points, by_x);
+    }
+
+    let (left, right) = by_x.split_at(by_x.len() / 2);
+    let mid_x = points[right[0]].x();
+    let mut closest = match (
+        closest_pair_recursive(points, left),
+        closest_pair_recursive(points, right),
+    ) {
+        (Some(left), Some(right)) => {
+            if left.2 <= right.2 {
+                left
+            } else {
+                right
+            }
+        }
+        (pair, None) | (None, pair) => pair?,
+    };
+
+    // Only pairs straddling the split and closer to it than the best distance can improve it.
+    let mut strip: Vec<usize> = by_x
+        .iter()
+        .copied()
+        .filter(|&index| (points[index].x() - mid_x).abs() < closest.2)
+        .collect();
+    strip.sort_by(|&a, &b| points[a].y().total_cmp(&points[b].y()));
+    for (offset, &a) in strip.iter().enumerate() {
+        for &b in &strip[offset + 1..] {
+            if points[b].y() - points[a].y() >= closest.2 {
+                break;
+            }
+            let distance = distance(points[a], points[b]);
+            if distance < closest.2 {
+                closest = (a, b, distance);
+            }
+        }
+    }
+    Some(closest)
+}
+
+/// Finds the two closest positions of a set.
+///
+/// Returns the indices of the two positions, the smallest first, and their distance, or `None`
+/// when there are fewer than two positions. Uses a divide and conquer search, falling back to
+/// comparing every pair for small sets.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::{
+///     geometry::closest_pair,
+///     Position,
+/// };
+///
+/// fn main() {
+///     let points = [
+///         Position::new(0.0, 0.0),
+///         Position::new(10.0, 10.0),
+///         Position::new(4.0, 3.0),
+///         Position::new(10.0, 11.0),
+///     ];
+///     assert_eq!(closest_pair(&points), Some((1, 3, 1.0)));
+///     assert_eq!(closest_pair(&points[..1]), None);
+///
+///     // Compare against every pair on pseudo-random positions.
+///     let mut state = 0x2545_f491_4f6c_dd1d_u64;
+///     let mut random = move || {
+///         state ^= state << 13;
+///         state ^= state >> 7;
+///         state ^= state << 17;
+///         (state >> 11) as f64 / (1u64 << 53) as f64 * 1000.0
+///     };
+///     let points: Vec<_> = (0..500)
+///         .map(|_| Position::new(random(), random()))
+///         .collect();
+///     let mut expected = f64::INFINITY;
+///     for (offset, &a) in points.iter().enumerate() {
+///         for &b in &points[offset + 1..] {
+///             expected = expected.min((b.x() - a.x()).hypot(b.y() - a.y()));
+///         }
+///     }
+///     let (a, b, distance) = closest_pair(&points).unwrap();
+///     assert!(a < b);
+///     assert_eq!(distance, expected);
+/// }
+/// ```
+pub fn closest_pair(points: &[Position]) -> Option<(usize, usize, f64)> {
+    let mut by_x: Vec<usize> = (0..points.len()).collect();
+    by_x.sort_by(|&a, &b| points[a].x().total_cmp(&points[b].x()));
+    closest_pair_recursive(points, &by_x).map(|(a, b, distance)| (a.min(b), a.max(b), distance))
+}
+
+/// Whether `point` lies on the segment from `a` to `b`, endpoints included.
+fn on_segment(a: Position, b: Position, point: Position) -> bool {
+    cross(a, b, point) == 0.0
+        && point.x() >= a.x().min(b.x())
+        && point.x() <= a.x().max(b.x())
+        && point.y() >= a.y().min(b.y())
+        && point.y() <= a.y().max(b.y())
+}
+
+/// Whether a polygon contains a point.
+///
+/// The polygon is described by its vertices in order, either clockwise or counterclockwise, the
+/// last vertex connecting back to the first. Uses the even-odd rule, so the regions of a
+/// self-intersecting polygon covered an even number of times are outside. Points lying on an edge
+/// or a vertex are always contained, which makes the test consistent between polygons sharing an
+/// edge. Polygons with fewer than three vertices contain only the points of their edges.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::{
+///     geometry::contains_point,
+///     Position,
+/// };
+///
+/// fn main() {
+///     let square = [
+///         Position::new(0.0, 0.0),
+///         Position::new(4.0, 0.0),
+///         Position::new(4.0, 4.0),
+///         Position::new(0.0, 4.0),
+///     ];
+///     assert!(contains_point(&square, Position::new(2.0, 2.0)));
+///     assert!(contains_point(&square, Position::new(4.0, 2.0)));
+///     assert!(contains_point(&square, Position::new(0.0, 0.0)));
+///     assert!(!contains_point(&square, Position::new(10.0, 2.0)));
+///
+///     // A U shape, open towards the top.
+///     let concave = [
+///         Position::new(0.0, 0.0),
+///         Position::new(1.0, 0.0),
+///         Position::new(1.0, 3.0),
+///         Position::new(2.0, 3.0),
+///         Position::new(2.0, 0.0),
+///         Position::new(3.0, 0.0),
+///         Position::new(3.0, 4.0),
+///         Position::new(0.0, 4.0),
+///     ];
+///     assert!(contains_point(&concave, Position::new(0.5, 1.0)));
+///     assert!(contains_point(&concave, Position::new(1.5, 3.5)));
+///     assert!(!contains_point(&concave, Position::new(1.5, 1.0)));
+///     assert!(!contains_point(&concave, Position::new(-5.0, -5.0)));
+///
+///     assert!(!contains_point(&[], Position::zero()));
+/// }
+/// ```
+pub fn contains_point(polygon: &[Position], point: Position) -> bool {
+    let edges = || {
+        polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    };
+    if edges().any(|(a, b)| on_segment(a, b, point)) {
+        return true;
+    }
+
+    // Count the crossings of a horizontal ray starting at the point. Edges are half-open along
+    // the vertical axis, so a ray through a vertex is counted once.
+    edges()
+        .filter(|&(a, b)| (a.y() > point.y()) != (b.y() > point.y()))
+        .filter(|&(a, b)| {
+            let x = a.x() + (point.y() - a.y()) / (b.y() - a.y()) * (b.x() - a.x());
+            point.x() < x
+        })
+        .count()
+        % 2
+        == 1
+}
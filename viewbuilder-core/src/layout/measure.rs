@@ -0,0 +1,97 @@
+//! [`Measurable`](crate::layout::Measurable) definitions.
+
+use super::*;
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        PoisonError,
+    },
+};
+
+/// Content able to report its preferred size.
+pub trait Measurable
+where
+    Self: SendSync,
+{
+    /// The size the content would like to occupy given a `proposal` from its parent.
+    fn intrinsic_size(&self, proposal: Size) -> Size;
+
+    /// Wraps the content so each distinct proposal is only measured once.
+    fn cached_measurable(self) -> MeasureCache<Self>
+    where
+        Self: Sized,
+    {
+        MeasureCache::new(self)
+    }
+}
+
+/// A [`Measurable`] memoizing the measurements of its content by proposal.
+///
+/// Proposals are compared by the bit patterns of their dimensions.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::atomic::{
+///     AtomicUsize,
+///     Ordering,
+/// };
+/// use viewbuilder::layout::*;
+///
+/// #[derive(Debug, Default)]
+/// struct Paragraph {
+///     measurements: AtomicUsize,
+/// }
+///
+/// impl Measurable for Paragraph {
+///     fn intrinsic_size(&self, proposal: Size) -> Size {
+///         self.measurements.fetch_add(1, Ordering::SeqCst);
+///         Size::new(proposal.width(), 1200.0 / proposal.width())
+///     }
+/// }
+///
+/// fn main() {
+///     let paragraph = Paragraph::default().cached_measurable();
+///     let narrow = Size::new(100.0, 500.0);
+///     let wide = Size::new(400.0, 500.0);
+///
+///     assert_eq!(paragraph.intrinsic_size(narrow), Size::new(100.0, 12.0));
+///     assert_eq!(paragraph.intrinsic_size(narrow), Size::new(100.0, 12.0));
+///     assert_eq!(paragraph.intrinsic_size(wide), Size::new(400.0, 3.0));
+///     assert_eq!(paragraph.content().measurements.load(Ordering::SeqCst), 2);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MeasureCache<Content> {
+    content: Content,
+    sizes: Mutex<HashMap<[u64; 2], Size>>,
+}
+
+impl<Content> MeasureCache<Content> {
+    /// Wraps `content` with an empty cache.
+    pub fn new(content: Content) -> Self {
+        Self {
+            content,
+            sizes: Mutex::default(),
+        }
+    }
+
+    /// The measured content.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+}
+
+impl<Content> Measurable for MeasureCache<Content>
+where
+    Content: Measurable,
+{
+    fn intrinsic_size(&self, proposal: Size) -> Size {
+        let key = [proposal.width().to_bits(), proposal.height().to_bits()];
+        *self
+            .sizes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key)
+            .or_insert_with(|| self.content.intrinsic_size(proposal))
+    }
+}
@@ -0,0 +1,64 @@
+//! Layout primitives and geometry.
+
+mod affine;
+mod alignment;
+mod angle;
+mod axis;
+mod direction;
+mod edge_insets;
+pub mod geometry;
+mod measure;
+pub mod packing;
+mod position;
+mod protocol;
+mod rect;
+mod shape;
+mod size;
+pub mod snap;
+mod transforms;
+mod vector;
+
+use crate::prelude::*;
+
+pub use affine::AffineTransform;
+pub use alignment::{
+    Alignment,
+    Guide,
+    HorizontalAlignment,
+    VerticalAlignment,
+};
+pub use angle::Angle;
+pub use axis::Axis;
+pub use direction::{
+    HorizontalEdge,
+    LayoutDirection,
+};
+pub use edge_insets::{
+    EdgeInsets,
+    PhysicalEdgeInsets,
+};
+pub use measure::{
+    Measurable,
+    MeasureCache,
+};
+pub use position::Position;
+pub use protocol::{
+    HStackLayout,
+    Layout,
+    VStackLayout,
+    ZStackLayout,
+};
+pub use rect::Rect;
+pub use shape::{
+    CornerRadii,
+    PhysicalCornerRadii,
+    RoundedRectangle,
+    Shape,
+};
+pub use size::Size;
+pub use transforms::{
+    Rotate,
+    Scale,
+    Translate,
+};
+pub use vector::Vector;
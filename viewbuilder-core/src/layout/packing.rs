@@ -0,0 +1,72 @@
+//! Rectangle packing helpers.
+
+use super::*;
+
+/// A horizontal strip of the bin holding rectangles side by side.
+#[derive(Debug)]
+struct Shelf {
+    y: f64,
+    height: f64,
+    width: f64,
+}
+
+/// Packs rectangles of the given `sizes` into a `bin`.
+///
+/// Uses a shelf first-fit algorithm: each rectangle goes on the first shelf tall enough with room
+/// left, or on a new shelf opened under the previous ones. The height of a shelf is the height of
+/// the rectangle opening it. Returns, for each size, the origin of its placement relative to the
+/// bin's origin, or `None` if it does not fit.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::{
+///     packing::pack,
+///     Position,
+///     Size,
+/// };
+///
+/// fn main() {
+///     let sizes = [
+///         Size::new(40.0, 30.0),
+///         Size::new(50.0, 20.0),
+///         Size::new(60.0, 25.0),
+///         Size::new(200.0, 10.0),
+///     ];
+///     let placements = pack(&sizes, Size::new(100.0, 100.0));
+///     assert_eq!(
+///         placements,
+///         vec![
+///             Some(Position::new(0.0, 0.0)),
+///             Some(Position::new(40.0, 0.0)),
+///             Some(Position::new(0.0, 30.0)),
+///             None,
+///         ]
+///     );
+/// }
+/// ```
+pub fn pack(sizes: &[Size], bin: Size) -> Vec<Option<Position>> {
+    let mut shelves: Vec<Shelf> = Vec::new();
+    sizes
+        .iter()
+        .map(|size| {
+            let fits_shelf = |shelf: &&mut Shelf| {
+                size.height() <= shelf.height && shelf.width + size.width() <= bin.width()
+            };
+            if let Some(shelf) = shelves.iter_mut().find(fits_shelf) {
+                let origin = Position::new(shelf.width, shelf.y);
+                shelf.width += size.width();
+                return Some(origin);
+            }
+
+            let y = shelves.last().map_or(0.0, |shelf| shelf.y + shelf.height);
+            if size.width() > bin.width() || y + size.height() > bin.height() {
+                return None;
+            }
+            shelves.push(Shelf {
+                y,
+                height: size.height(),
+                width: size.width(),
+            });
+            Some(Position::new(0.0, y))
+        })
+        .collect()
+}
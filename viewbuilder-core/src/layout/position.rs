@@ -0,0 +1,284 @@
+//! [`Position`](crate::layout::Position) definition.
+
+use super::*;
+use std::{
+    ops::{
+        Add,
+        AddAssign,
+        Neg,
+        Sub,
+        SubAssign,
+    },
+    simd::f64x2,
+};
+
+/// A position in a two dimensional space.
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::Position;
+///
+/// fn main() {
+///     let position = Position::new(1.0, 2.0);
+///     println!("{position:?}");
+/// # assert_eq!(position.x(), 1.0);
+/// # assert_eq!(position.y(), 2.0);
+/// }
+/// ```
+///
+/// Positions support component-wise arithmetic:
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::Position;
+///
+/// fn main() {
+///     let mut position = Position::new(1.0, 2.0) + Position::new(3.0, 4.0);
+///     assert_eq!(position, Position::new(4.0, 6.0));
+///     assert_eq!(position - Position::new(1.0, 1.0), Position::new(3.0, 5.0));
+///     assert_eq!(-position, Position::new(-4.0, -6.0));
+///
+///     position -= Position::new(4.0, 6.0);
+///     assert_eq!(position, Position::zero());
+///     position += Position::new(0.5, -0.5);
+///     assert_eq!(position, Position::new(0.5, -0.5));
+/// }
+/// ```
+///
+/// Positions convert to and from `[x, y]` arrays and `(x, y)` tuples:
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::Position;
+///
+/// fn main() {
+///     let position = Position::from([1.0, 2.0]);
+///     assert_eq!(position, Position::new(1.0, 2.0));
+///     assert_eq!(<[f64; 2]>::from(position), [1.0, 2.0]);
+///
+///     let position: Position = (3.0, 4.0).into();
+///     assert_eq!((position.x(), position.y()), (3.0, 4.0));
+///     assert_eq!(<(f64, f64)>::from(position), (3.0, 4.0));
+///
+///     let coordinates: [f64; 2] = Position::from((5.0, 6.0)).into();
+///     assert_eq!(Position::from(coordinates), Position::new(5.0, 6.0));
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Position(f64x2);
+
+impl Position {
+    /// Creates a new position from its coordinates.
+    pub fn new(x: f64, y: f64) -> Self {
+        Self(f64x2::from_array([x, y]))
+    }
+
+    /// The position at the origin of the coordinate space.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// The horizontal coordinate.
+    pub fn x(&self) -> f64 {
+        self.0[0]
+    }
+
+    /// The vertical coordinate.
+    pub fn y(&self) -> f64 {
+        self.0[1]
+    }
+
+    /// The coordinate along the `axis`.
+    pub fn main(&self, axis: Axis) -> f64 {
+        self.0[axis.index()]
+    }
+
+    /// The coordinate perpendicular to the `axis`.
+    pub fn cross(&self, axis: Axis) -> f64 {
+        self.main(axis.perpendicular())
+    }
+
+    /// Creates a position from its coordinates along and perpendicular to the `axis`.
+    pub(crate) fn from_axis(axis: Axis, main: f64, cross: f64) -> Self {
+        Self(f64x2::from_array(axis.components(main, cross)))
+    }
+
+    /// Whether both coordinates are neither infinite nor `NaN`.
+    pub fn is_finite(&self) -> bool {
+        self.x().is_finite() && self.y().is_finite()
+    }
+
+    /// Replaces the non-finite coordinates with the matching coordinate of `fallback`.
+    ///
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::Position;
+    ///
+    /// fn main() {
+    ///     let position = Position::new(f64::NAN, 2.0);
+    ///     assert!(!position.is_finite());
+    ///
+    ///     let sanitized = position.sanitized(Position::zero());
+    ///     assert!(sanitized.is_finite());
+    ///     assert_eq!(sanitized, Position::new(0.0, 2.0));
+    /// }
+    /// ```
+    pub fn sanitized(&self, fallback: Position) -> Position {
+        let pick = |value: f64, fallback: f64| if value.is_finite() { value } else { fallback };
+        Position::new(pick(self.x(), fallback.x()), pick(self.y(), fallback.y()))
+    }
+
+    /// Whether both coordinates are within `epsilon` of the matching coordinate of `other`.
+    ///
+    /// Positions are usually the result of floating point computations, so comparing them
+    /// exactly is fragile. [`PartialEq`] is still exact: a tolerance makes equality
+    /// non-transitive, which would break the code relying on it, like deduplication.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::Position;
+    ///
+    /// fn main() {
+    ///     let position = Position::new(1.0, 2.0);
+    ///     assert_ne!(Position::new(0.1 + 0.2, 0.0), Position::new(0.3, 0.0));
+    ///     assert!(Position::new(0.1 + 0.2, 0.0).approx_eq(Position::new(0.3, 0.0), 1e-12));
+    ///
+    ///     assert!(position.approx_eq(Position::new(1.25, 2.0), 0.25));
+    ///     assert!(position.approx_eq(Position::new(0.75, 2.0), 0.25));
+    ///     assert!(!position.approx_eq(Position::new(1.5, 2.0), 0.25));
+    ///     assert!(position.approx_eq(Position::new(1.0, 2.25), 0.25));
+    ///     assert!(position.approx_eq(Position::new(1.0, 1.75), 0.25));
+    ///     assert!(!position.approx_eq(Position::new(1.0, 1.5), 0.25));
+    ///     assert!(!position.approx_eq(Position::new(f64::NAN, 2.0), 0.25));
+    /// }
+    /// ```
+    pub fn approx_eq(&self, other: Position, epsilon: f64) -> bool {
+        (self.0 - other.0)
+            .to_array()
+            .iter()
+            .all(|difference| difference.abs() <= epsilon)
+    }
+
+    /// Linearly interpolates between the position and `other`.
+    ///
+    /// `t` is not clamped: values outside `[0, 1]` extrapolate along the line through both
+    /// positions.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::Position;
+    ///
+    /// fn main() {
+    ///     let (from, to) = (Position::new(0.0, 10.0), Position::new(4.0, 20.0));
+    ///     assert_eq!(from.lerp(to, 0.0), from);
+    ///     assert_eq!(from.lerp(to, 0.5), Position::new(2.0, 15.0));
+    ///     assert_eq!(from.lerp(to, 1.0), to);
+    ///     assert_eq!(from.lerp(to, 2.0), Position::new(8.0, 30.0));
+    /// }
+    /// ```
+    pub fn lerp(&self, other: Position, t: f64) -> Position {
+        Self(self.0 + (other.0 - self.0) * f64x2::splat(t))
+    }
+
+    /// The homogeneous coordinates of the position, with a `w` component of `1.0`.
+    ///
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::Position;
+    ///
+    /// fn main() {
+    ///     let position = Position::new(1.0, 2.0);
+    ///     let coordinates = position.to_homogeneous();
+    ///     assert_eq!(coordinates, [1.0, 2.0, 1.0]);
+    ///     assert_eq!(Position::from_homogeneous(coordinates), Some(position));
+    /// }
+    /// ```
+    pub fn to_homogeneous(&self) -> [f64; 3] {
+        [self.x(), self.y(), 1.0]
+    }
+
+    /// Creates a position from homogeneous coordinates, dividing them by their `w` component.
+    ///
+    /// Returns `None` for points at infinity, whose `w` component is zero.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::Position;
+    ///
+    /// fn main() {
+    ///     assert_eq!(
+    ///         Position::from_homogeneous([2.0, 4.0, 2.0]),
+    ///         Some(Position::new(1.0, 2.0)),
+    ///     );
+    ///     assert_eq!(Position::from_homogeneous([2.0, 4.0, 0.0]), None);
+    /// }
+    /// ```
+    pub fn from_homogeneous([x, y, w]: [f64; 3]) -> Option<Self> {
+        (w != 0.0).then(|| Self::new(x / w, y / w))
+    }
+}
+
+impl Debug for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Position")
+            .field("x", &self.x())
+            .field("y", &self.y())
+            .finish()
+    }
+}
+
+impl Add for Position {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Position {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Position {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Position {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Position {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl From<[f64; 2]> for Position {
+    fn from(coordinates: [f64; 2]) -> Self {
+        Self(f64x2::from_array(coordinates))
+    }
+}
+
+impl From<(f64, f64)> for Position {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Position> for [f64; 2] {
+    fn from(position: Position) -> Self {
+        position.0.to_array()
+    }
+}
+
+impl From<Position> for (f64, f64) {
+    fn from(position: Position) -> Self {
+        let [x, y] = position.0.to_array();
+        (x, y)
+    }
+}
@@ -0,0 +1,338 @@
+//! [`Layout`](crate::layout::Layout) protocol and built-in implementations.
+
+use super::*;
+
+/// Describes how a container sizes itself and places its subviews.
+///
+/// Subviews are described by their sizes, in the order they are laid out.
+pub trait Layout
+where
+    Self: SendSync,
+{
+    /// The size of the container given a `proposal` from its parent.
+    fn size_that_fits(&self, proposal: Size, subviews: &[Size]) -> Size;
+
+    /// The origins of the `subviews` when the container occupies `bounds`.
+    fn place_subviews(&self, bounds: Rect, subviews: &[Size]) -> Vec<Position>;
+
+    /// The indices of the subviews in the order they are rendered, given their `z_indices`.
+    ///
+    /// Subviews are rendered from the lowest z-index to the highest, those sharing a z-index
+    /// keeping their layout order, so later subviews are drawn on top. Only the rendering order is
+    /// affected, subviews are still sized and placed in layout order.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::*;
+    ///
+    /// fn main() {
+    ///     let layout = ZStackLayout::default();
+    ///     assert_eq!(layout.render_order(&[2.0, 0.0, 1.0]), vec![1, 2, 0]);
+    ///     assert_eq!(layout.render_order(&[1.0, 0.0, 0.0]), vec![1, 2, 0]);
+    /// }
+    /// ```
+    fn render_order(&self, z_indices: &[f64]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..z_indices.len()).collect();
+        order.sort_by(|&a, &b| z_indices[a].total_cmp(&z_indices[b]));
+        order
+    }
+}
+
+/// Size of `subviews` laid one after another along the `axis`, `spacing` apart.
+fn stacked_size(axis: Axis, spacing: f64, subviews: &[Size]) -> Size {
+    let total: f64 = subviews.iter().map(|size| size.main(axis)).sum();
+    let gaps = subviews.len().max(1) - 1;
+    Size::from_axis(
+        axis,
+        total + spacing * gaps as f64,
+        subviews
+            .iter()
+            .map(|size| size.cross(axis))
+            .fold(0.0, f64::max),
+    )
+}
+
+/// Origins of `subviews` laid one after another along the `axis`, `spacing` apart, with their
+/// alignment guides lined up across it.
+///
+/// The guide of a subview defaults to `fraction` of its cross length unless overridden in
+/// `guides`. The aligned subviews are then positioned within the cross length of `bounds` per the
+/// same `fraction`.
+fn stacked_origins(
+    axis: Axis,
+    spacing: f64,
+    fraction: f64,
+    bounds: Rect,
+    subviews: &[Size],
+    guides: &[Option<f64>],
+) -> Vec<Position> {
+    let guides: Vec<f64> = subviews
+        .iter()
+        .enumerate()
+        .map(|(index, size)| {
+            guides
+                .get(index)
+                .copied()
+                .flatten()
+                .unwrap_or(size.cross(axis) * fraction)
+        })
+        .collect();
+    let before = guides.iter().copied().fold(0.0, f64::max);
+    let after = subviews
+        .iter()
+        .zip(&guides)
+        .map(|(size, guide)| size.cross(axis) - guide)
+        .fold(0.0, f64::max);
+    let origin = bounds.origin();
+    let line = origin.cross(axis) + (bounds.cross(axis) - before - after) * fraction + before;
+
+    let mut main = origin.main(axis);
+    subviews
+        .iter()
+        .zip(guides)
+        .map(|(size, guide)| {
+            let position = Position::from_axis(axis, main, line - guide);
+            main += size.main(axis) + spacing;
+            position
+        })
+        .collect()
+}
+
+/// Lays subviews out horizontally, from left to right, aligning them vertically.
+///
+/// Subviews keep their size: the stack is as wide as its subviews and the spacing between them,
+/// and as tall as its tallest subview. Subviews are centered vertically unless another alignment
+/// is given.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::*;
+///
+/// fn main() {
+///     let layout = HStackLayout::new(5.0);
+///     let subviews = [Size::new(10.0, 10.0); 3];
+///     let size = layout.size_that_fits(Size::new(100.0, 100.0), &subviews);
+///     assert_eq!(size, Size::new(40.0, 10.0));
+///
+///     let origins = layout.place_subviews(Rect::new(Position::zero(), size), &subviews);
+///     assert_eq!(
+///         origins,
+///         vec![
+///             Position::new(0.0, 0.0),
+///             Position::new(15.0, 0.0),
+///             Position::new(30.0, 0.0),
+///         ]
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HStackLayout {
+    spacing: f64,
+    alignment: VerticalAlignment,
+}
+
+impl HStackLayout {
+    /// Creates a horizontal stack layout with `spacing` between its subviews.
+    pub fn new(spacing: f64) -> Self {
+        Self {
+            spacing,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the vertical alignment of the subviews.
+    pub fn with_alignment(self, alignment: VerticalAlignment) -> Self {
+        Self { alignment, ..self }
+    }
+
+    /// The distance between adjacent subviews.
+    pub fn spacing(&self) -> f64 {
+        self.spacing
+    }
+
+    /// The vertical alignment of the subviews.
+    pub fn alignment(&self) -> VerticalAlignment {
+        self.alignment
+    }
+
+    /// Places the `subviews` lining up their alignment guides.
+    ///
+    /// `guides` holds, for each subview, the vertical offset of its alignment guide from its top
+    /// edge, `None` keeping the default guide of the stack's alignment.
+    pub fn place_subviews_with_guides(
+        &self,
+        bounds: Rect,
+        subviews: &[Size],
+        guides: &[Option<f64>],
+    ) -> Vec<Position> {
+        stacked_origins(
+            Axis::Horizontal,
+            self.spacing,
+            self.alignment.fraction(),
+            bounds,
+            subviews,
+            guides,
+        )
+    }
+}
+
+impl Layout for HStackLayout {
+    fn size_that_fits(&self, _proposal: Size, subviews: &[Size]) -> Size {
+        stacked_size(Axis::Horizontal, self.spacing, subviews)
+    }
+
+    fn place_subviews(&self, bounds: Rect, subviews: &[Size]) -> Vec<Position> {
+        self.place_subviews_with_guides(bounds, subviews, &[])
+    }
+}
+
+/// Lays subviews out vertically, from top to bottom, aligning them horizontally.
+///
+/// Subviews keep their size: the stack is as tall as its subviews and the spacing between them,
+/// and as wide as its widest subview. Subviews are centered horizontally unless another alignment
+/// is given.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::*;
+///
+/// fn main() {
+///     let layout = VStackLayout::new(2.0);
+///     let subviews = [Size::new(10.0, 4.0), Size::new(20.0, 6.0)];
+///     let size = layout.size_that_fits(Size::new(100.0, 100.0), &subviews);
+///     assert_eq!(size, Size::new(20.0, 12.0));
+///
+///     let bounds = Rect::new(Position::zero(), size);
+///     let origins = layout.place_subviews(bounds, &subviews);
+///     assert_eq!(
+///         origins,
+///         vec![Position::new(5.0, 0.0), Position::new(0.0, 6.0)]
+///     );
+///
+///     let leading = layout.with_alignment(HorizontalAlignment::Leading);
+///     let origins = leading.place_subviews(bounds, &subviews);
+///     assert_eq!(
+///         origins,
+///         vec![Position::new(0.0, 0.0), Position::new(0.0, 6.0)]
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VStackLayout {
+    spacing: f64,
+    alignment: HorizontalAlignment,
+}
+
+impl VStackLayout {
+    /// Creates a vertical stack layout with `spacing` between its subviews.
+    pub fn new(spacing: f64) -> Self {
+        Self {
+            spacing,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the horizontal alignment of the subviews.
+    pub fn with_alignment(self, alignment: HorizontalAlignment) -> Self {
+        Self { alignment, ..self }
+    }
+
+    /// The distance between adjacent subviews.
+    pub fn spacing(&self) -> f64 {
+        self.spacing
+    }
+
+    /// The horizontal alignment of the subviews.
+    pub fn alignment(&self) -> HorizontalAlignment {
+        self.alignment
+    }
+
+    /// Places the `subviews` lining up their alignment guides.
+    ///
+    /// `guides` holds, for each subview, the horizontal offset of its alignment guide from its
+    /// leading edge, `None` keeping the default guide of the stack's alignment.
+    pub fn place_subviews_with_guides(
+        &self,
+        bounds: Rect,
+        subviews: &[Size],
+        guides: &[Option<f64>],
+    ) -> Vec<Position> {
+        stacked_origins(
+            Axis::Vertical,
+            self.spacing,
+            self.alignment.fraction(),
+            bounds,
+            subviews,
+            guides,
+        )
+    }
+}
+
+impl Layout for VStackLayout {
+    fn size_that_fits(&self, _proposal: Size, subviews: &[Size]) -> Size {
+        stacked_size(Axis::Vertical, self.spacing, subviews)
+    }
+
+    fn place_subviews(&self, bounds: Rect, subviews: &[Size]) -> Vec<Position> {
+        self.place_subviews_with_guides(bounds, subviews, &[])
+    }
+}
+
+/// Overlays subviews on top of each other, aligning them within the stack.
+///
+/// The stack is as large as its largest subview along each axis and subviews keep their size.
+/// Subviews are centered unless another alignment is given, and rendered in layout order unless
+/// reordered by their z-index, see [`Layout::render_order`].
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::*;
+///
+/// fn main() {
+///     let layout = ZStackLayout::default();
+///     let subviews = [Size::new(10.0, 40.0), Size::new(20.0, 10.0)];
+///     let size = layout.size_that_fits(Size::new(100.0, 100.0), &subviews);
+///     assert_eq!(size, Size::new(20.0, 40.0));
+///
+///     let bounds = Rect::new(Position::zero(), size);
+///     let origins = layout.place_subviews(bounds, &subviews);
+///     assert_eq!(
+///         origins,
+///         vec![Position::new(5.0, 0.0), Position::new(0.0, 15.0)]
+///     );
+///
+///     let top_leading = ZStackLayout::new(Alignment::TOP_LEADING);
+///     let origins = top_leading.place_subviews(bounds, &subviews);
+///     assert_eq!(origins, vec![Position::zero(), Position::zero()]);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ZStackLayout {
+    alignment: Alignment,
+}
+
+impl ZStackLayout {
+    /// Creates an overlaying stack layout aligning its subviews per `alignment`.
+    pub fn new(alignment: Alignment) -> Self {
+        Self { alignment }
+    }
+
+    /// The alignment of the subviews.
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+}
+
+impl Layout for ZStackLayout {
+    fn size_that_fits(&self, _proposal: Size, subviews: &[Size]) -> Size {
+        subviews.iter().fold(Size::zero(), |size, subview| {
+            Size::new(
+                size.width().max(subview.width()),
+                size.height().max(subview.height()),
+            )
+        })
+    }
+
+    fn place_subviews(&self, bounds: Rect, subviews: &[Size]) -> Vec<Position> {
+        subviews
+            .iter()
+            .map(|&subview| bounds.place(subview, self.alignment).origin())
+            .collect()
+    }
+}
@@ -0,0 +1,322 @@
+//! [`Rect`](crate::layout::Rect) definition.
+
+use super::*;
+use crate::transform::Transform;
+
+/// An axis aligned rectangle described by its origin and size.
+///
+/// The origin is the corner with the smallest coordinates.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::{
+///     Position,
+///     Rect,
+///     Size,
+/// };
+///
+/// fn main() {
+///     let rect = Rect::new(Position::new(1.0, 2.0), Size::new(3.0, 4.0));
+///     println!("{rect:?}");
+/// # assert_eq!(rect.origin(), Position::new(1.0, 2.0));
+/// # assert_eq!(rect.size(), Size::new(3.0, 4.0));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    origin: Position,
+    size: Size,
+}
+
+impl Rect {
+    /// Creates a new rectangle from its origin and size.
+    pub fn new(origin: Position, size: Size) -> Self {
+        Self { origin, size }
+    }
+
+    /// Creates the smallest rectangle having `a` and `b` as opposite corners, in any order.
+    ///
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::*;
+    ///
+    /// fn main() {
+    ///     let expected = Rect::new(Position::new(1.0, 2.0), Size::new(3.0, 4.0));
+    ///     let (a, b) = (Position::new(1.0, 2.0), Position::new(4.0, 6.0));
+    ///     assert_eq!(Rect::from_points(a, b), expected);
+    ///     assert_eq!(Rect::from_points(b, a), expected);
+    ///     assert_eq!(
+    ///         Rect::from_points(Position::new(1.0, 6.0), Position::new(4.0, 2.0)),
+    ///         expected
+    ///     );
+    ///     assert_eq!(Rect::from_points(a, a).size(), Size::zero());
+    /// }
+    /// ```
+    pub fn from_points(a: Position, b: Position) -> Self {
+        let origin = Position::new(a.x().min(b.x()), a.y().min(b.y()));
+        let size = Size::new((a.x() - b.x()).abs(), (a.y() - b.y()).abs());
+        Self::new(origin, size)
+    }
+
+    /// The corner with the smallest coordinates.
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    /// The dimensions of the rectangle.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// The length of the rectangle along the `axis`.
+    pub fn main(&self, axis: Axis) -> f64 {
+        self.size.main(axis)
+    }
+
+    /// The length of the rectangle perpendicular to the `axis`.
+    pub fn cross(&self, axis: Axis) -> f64 {
+        self.size.cross(axis)
+    }
+
+    /// The smallest horizontal coordinate.
+    pub fn min_x(&self) -> f64 {
+        self.origin.x()
+    }
+
+    /// The smallest vertical coordinate.
+    pub fn min_y(&self) -> f64 {
+        self.origin.y()
+    }
+
+    /// The largest horizontal coordinate.
+    pub fn max_x(&self) -> f64 {
+        self.origin.x() + self.size.width()
+    }
+
+    /// The largest vertical coordinate.
+    pub fn max_y(&self) -> f64 {
+        self.origin.y() + self.size.height()
+    }
+
+    /// The center point of the rectangle.
+    pub fn center(&self) -> Position {
+        self.anchor_point(Alignment::CENTER)
+    }
+
+    /// Whether `point` lies within the rectangle, edges included.
+    ///
+    /// A zero-area rectangle still contains the points of its degenerate edge.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::*;
+    ///
+    /// fn main() {
+    ///     let rect = Rect::new(Position::new(1.0, 1.0), Size::new(2.0, 2.0));
+    ///     assert_eq!(rect.center(), Position::new(2.0, 2.0));
+    ///     assert!(rect.contains(rect.center()));
+    ///     assert!(rect.contains(Position::new(1.0, 1.0)));
+    ///     assert!(rect.contains(Position::new(3.0, 3.0)));
+    ///     assert!(rect.contains(Position::new(3.0, 1.5)));
+    ///     assert!(!rect.contains(Position::new(3.1, 1.5)));
+    ///     assert!(!rect.contains(Position::new(0.0, 0.0)));
+    ///
+    ///     let line = Rect::new(Position::zero(), Size::new(2.0, 0.0));
+    ///     assert!(line.contains(Position::new(1.0, 0.0)));
+    ///     assert!(!line.contains(Position::new(1.0, 0.1)));
+    ///     let point = Rect::new(Position::new(5.0, 5.0), Size::zero());
+    ///     assert!(point.contains(Position::new(5.0, 5.0)));
+    ///     assert_eq!(point.center(), Position::new(5.0, 5.0));
+    /// }
+    /// ```
+    pub fn contains(&self, point: Position) -> bool {
+        (self.min_x()..=self.max_x()).contains(&point.x())
+            && (self.min_y()..=self.max_y()).contains(&point.y())
+    }
+
+    /// The point of the rectangle designated by an `alignment`.
+    ///
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::*;
+    ///
+    /// fn main() {
+    ///     let rect = Rect::new(Position::new(10.0, 20.0), Size::new(100.0, 50.0));
+    ///     assert_eq!(
+    ///         rect.anchor_point(Alignment::CENTER),
+    ///         Position::new(60.0, 45.0)
+    ///     );
+    ///     assert_eq!(
+    ///         rect.anchor_point(Alignment::TOP_LEADING),
+    ///         Position::new(10.0, 20.0)
+    ///     );
+    ///     assert_eq!(
+    ///         rect.anchor_point(Alignment::BOTTOM_TRAILING),
+    ///         Position::new(110.0, 70.0)
+    ///     );
+    /// }
+    /// ```
+    pub fn anchor_point(&self, alignment: Alignment) -> Position {
+        let Self { origin, size } = self;
+        Position::new(
+            origin.x() + size.width() * alignment.horizontal().fraction(),
+            origin.y() + size.height() * alignment.vertical().fraction(),
+        )
+    }
+
+    /// The frame of a `child` size placed within the rectangle according to an `alignment`.
+    ///
+    /// The anchor points of the child and the rectangle designated by the alignment coincide, so a
+    /// child larger than the rectangle overflows it evenly around that point.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::*;
+    ///
+    /// fn main() {
+    ///     let parent = Rect::new(Position::zero(), Size::new(100.0, 100.0));
+    ///     let child = Size::new(20.0, 10.0);
+    ///     assert_eq!(
+    ///         parent.place(child, Alignment::CENTER),
+    ///         Rect::new(Position::new(40.0, 45.0), child)
+    ///     );
+    ///
+    ///     let parent = Rect::new(Position::new(5.0, 7.0), Size::new(100.0, 100.0));
+    ///     assert_eq!(
+    ///         parent.place(child, Alignment::TOP_LEADING),
+    ///         Rect::new(parent.origin(), child)
+    ///     );
+    ///     assert_eq!(
+    ///         parent.place(child, Alignment::BOTTOM_TRAILING).origin(),
+    ///         Position::new(85.0, 97.0)
+    ///     );
+    /// }
+    /// ```
+    pub fn place(&self, child: Size, alignment: Alignment) -> Rect {
+        let anchor = self.anchor_point(alignment);
+        let origin = Position::new(
+            anchor.x() - child.width() * alignment.horizontal().fraction(),
+            anchor.y() - child.height() * alignment.vertical().fraction(),
+        );
+        Rect::new(origin, child)
+    }
+
+    /// Applies a position transform to the corners of the rectangle and returns their bounding
+    /// rectangle.
+    ///
+    /// Transforms that do not preserve axis alignment, like rotations, yield the axis aligned
+    /// rectangle enclosing the transformed corners. Only the corners are transformed, so the shape
+    /// of the rectangle is lost for non-affine transforms.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::{
+    ///     layout::*,
+    ///     transform::*,
+    /// };
+    ///
+    /// #[derive(Debug)]
+    /// struct QuarterTurn;
+    ///
+    /// impl OnceTransform<Position> for QuarterTurn {
+    ///     type Output = Position;
+    ///
+    ///     fn apply_once(self, input: Position) -> Self::Output {
+    ///         self.apply(input)
+    ///     }
+    /// }
+    ///
+    /// impl MutableTransform<Position> for QuarterTurn {
+    ///     fn apply_mut(&mut self, input: Position) -> Self::Output {
+    ///         self.apply(input)
+    ///     }
+    /// }
+    ///
+    /// impl Transform<Position> for QuarterTurn {
+    ///     fn apply(&self, input: Position) -> Self::Output {
+    ///         let (sin, cos) = std::f64::consts::FRAC_PI_4.sin_cos();
+    ///         Position::new(
+    ///             input.x() * cos - input.y() * sin,
+    ///             input.x() * sin + input.y() * cos,
+    ///         )
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let rect = Rect::new(Position::zero(), Size::new(1.0, 1.0));
+    ///     let enclosing = rect.transform_by(&QuarterTurn);
+    ///     println!("{enclosing:?}");
+    /// # let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+    /// # let half_diagonal = std::f64::consts::FRAC_1_SQRT_2;
+    /// # assert!(close(enclosing.origin().x(), -half_diagonal));
+    /// # assert!(close(enclosing.origin().y(), 0.0));
+    /// # assert!(close(enclosing.size().width(), 2.0 * half_diagonal));
+    /// # assert!(close(enclosing.size().height(), 2.0 * half_diagonal));
+    /// }
+    /// ```
+    pub fn transform_by<TransformType>(&self, transform: &TransformType) -> Rect
+    where
+        TransformType: Transform<Position, Output = Position>,
+    {
+        let (min_x, min_y) = (self.min_x(), self.min_y());
+        let (max_x, max_y) = (self.max_x(), self.max_y());
+        let corners = [
+            Position::new(min_x, min_y),
+            Position::new(max_x, min_y),
+            Position::new(max_x, max_y),
+            Position::new(min_x, max_y),
+        ];
+
+        let (min, max) = corners.iter().map(|&corner| transform.apply(corner)).fold(
+            (
+                Position::new(f64::INFINITY, f64::INFINITY),
+                Position::new(f64::NEG_INFINITY, f64::NEG_INFINITY),
+            ),
+            |(min, max), corner| {
+                (
+                    Position::new(min.x().min(corner.x()), min.y().min(corner.y())),
+                    Position::new(max.x().max(corner.x()), max.y().max(corner.y())),
+                )
+            },
+        );
+        Rect::new(min, Size::new(max.x() - min.x(), max.y() - min.y()))
+    }
+
+    /// Whether the segment from `a` to `b` touches the rectangle, edges included.
+    ///
+    /// The segment is clipped against the horizontal and vertical slabs of the rectangle
+    /// (Liang–Barsky), so segments lying entirely inside the rectangle intersect it while segments
+    /// whose line only crosses it outside of their endpoints do not.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::*;
+    ///
+    /// fn main() {
+    ///     let rect = Rect::new(Position::zero(), Size::new(10.0, 10.0));
+    ///     assert!(rect.intersects_segment(Position::new(-1.0, 5.0), Position::new(11.0, 5.0)));
+    ///     assert!(rect.intersects_segment(Position::new(2.0, 2.0), Position::new(3.0, 3.0)));
+    ///     assert!(!rect.intersects_segment(Position::new(12.0, 0.0), Position::new(20.0, -8.0)));
+    /// }
+    /// ```
+    pub fn intersects_segment(&self, a: Position, b: Position) -> bool {
+        let Self { origin, size } = self;
+        let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+        let slabs = [
+            (-dx, a.x() - origin.x()),
+            (dx, origin.x() + size.width() - a.x()),
+            (-dy, a.y() - origin.y()),
+            (dy, origin.y() + size.height() - a.y()),
+        ];
+
+        let (mut enter, mut exit) = (0.0_f64, 1.0_f64);
+        for (p, q) in slabs {
+            if p == 0.0 {
+                // The segment is parallel to the slab and must start within it.
+                if q < 0.0 {
+                    return false;
+                }
+            } else if p < 0.0 {
+                enter = enter.max(q / p);
+            } else {
+                exit = exit.min(q / p);
+            }
+        }
+        enter <= exit
+    }
+}
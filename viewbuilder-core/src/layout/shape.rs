@@ -0,0 +1,286 @@
+//! [`Shape`](crate::layout::Shape) and [`RoundedRectangle`](crate::layout::RoundedRectangle)
+//! definitions.
+
+use super::*;
+use std::f64::consts::{
+    FRAC_PI_2,
+    PI,
+};
+
+/// A two dimensional shape fitted to a rectangle.
+pub trait Shape
+where
+    Self: SendSync,
+{
+    /// The outline of the shape fitted to `rect`, as a closed polyline.
+    ///
+    /// The last vertex connects back to the first, so it is not repeated.
+    fn path_in(&self, rect: Rect) -> Vec<Position>;
+}
+
+/// The radius of each corner of a rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CornerRadii {
+    top_leading: f64,
+    top_trailing: f64,
+    bottom_trailing: f64,
+    bottom_leading: f64,
+}
+
+impl CornerRadii {
+    /// The radii of square corners.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Creates radii from the radius of each corner, clockwise from the top leading one.
+    pub const fn new(
+        top_leading: f64,
+        top_trailing: f64,
+        bottom_trailing: f64,
+        bottom_leading: f64,
+    ) -> Self {
+        Self {
+            top_leading,
+            top_trailing,
+            bottom_trailing,
+            bottom_leading,
+        }
+    }
+
+    /// Creates radii of the same `radius` on every corner.
+    pub const fn all(radius: f64) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// The radius of the top leading corner.
+    pub fn top_leading(&self) -> f64 {
+        self.top_leading
+    }
+
+    /// The radius of the top trailing corner.
+    pub fn top_trailing(&self) -> f64 {
+        self.top_trailing
+    }
+
+    /// The radius of the bottom trailing corner.
+    pub fn bottom_trailing(&self) -> f64 {
+        self.bottom_trailing
+    }
+
+    /// The radius of the bottom leading corner.
+    pub fn bottom_leading(&self) -> f64 {
+        self.bottom_leading
+    }
+
+    /// The physical radii under a layout `direction`.
+    ///
+    /// The leading corners are on the left edge from left to right and on the right edge from
+    /// right to left.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::*;
+    ///
+    /// fn main() {
+    ///     let radii = CornerRadii::new(8.0, 0.0, 0.0, 4.0);
+    ///     let ltr = radii.resolve(LayoutDirection::LeftToRight);
+    ///     assert_eq!((ltr.top_left(), ltr.bottom_left()), (8.0, 4.0));
+    ///     let rtl = radii.resolve(LayoutDirection::RightToLeft);
+    ///     assert_eq!((rtl.top_right(), rtl.bottom_right()), (8.0, 4.0));
+    ///     assert_eq!((rtl.top_left(), rtl.bottom_left()), (0.0, 0.0));
+    /// }
+    /// ```
+    pub fn resolve(&self, direction: LayoutDirection) -> PhysicalCornerRadii {
+        match direction {
+            LayoutDirection::LeftToRight => PhysicalCornerRadii::new(
+                self.top_leading,
+                self.top_trailing,
+                self.bottom_trailing,
+                self.bottom_leading,
+            ),
+            LayoutDirection::RightToLeft => PhysicalCornerRadii::new(
+                self.top_trailing,
+                self.top_leading,
+                self.bottom_leading,
+                self.bottom_trailing,
+            ),
+        }
+    }
+}
+
+/// The radius of each physical corner of a rectangle.
+///
+/// Obtained by resolving [`CornerRadii`] under a layout direction with [`CornerRadii::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PhysicalCornerRadii {
+    top_left: f64,
+    top_right: f64,
+    bottom_right: f64,
+    bottom_left: f64,
+}
+
+impl PhysicalCornerRadii {
+    /// The radii of square corners.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Creates radii from the radius of each corner, clockwise from the top left one.
+    pub const fn new(top_left: f64, top_right: f64, bottom_right: f64, bottom_left: f64) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// The radius of the top left corner.
+    pub fn top_left(&self) -> f64 {
+        self.top_left
+    }
+
+    /// The radius of the top right corner.
+    pub fn top_right(&self) -> f64 {
+        self.top_right
+    }
+
+    /// The radius of the bottom right corner.
+    pub fn bottom_right(&self) -> f64 {
+        self.bottom_right
+    }
+
+    /// The radius of the bottom left corner.
+    pub fn bottom_left(&self) -> f64 {
+        self.bottom_left
+    }
+}
+
+/// A rectangle with rounded corners.
+///
+/// Each corner is approximated by a polyline of `segments` segments. Radii are clamped between
+/// zero and half the smaller side of the rectangle, so opposing corners never overlap. Corners of
+/// zero radius are square and contribute a single vertex. The radii are resolved to physical
+/// corners under the [`layout_direction`](RoundedRectangle::layout_direction) of the rectangle,
+/// left to right by default.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::*;
+///
+/// fn main() {
+///     let rect = Rect::new(Position::zero(), Size::new(100.0, 40.0));
+///     let card = RoundedRectangle::uniform(8.0, 4);
+///     let path = card.path_in(rect);
+///     assert_eq!(path.len(), 4 * 5);
+///     assert!(path[0].approx_eq(Position::new(0.0, 8.0), 1e-12));
+///     assert!(path[4].approx_eq(Position::new(8.0, 0.0), 1e-12));
+///     assert!(path.iter().all(|&point| rect.contains(point)));
+///
+///     // The radius is clamped to half the height.
+///     let capsule = RoundedRectangle::uniform(50.0, 4);
+///     let path = capsule.path_in(rect);
+///     assert!(path[0].approx_eq(Position::new(0.0, 20.0), 1e-12));
+///     assert!(path[4].approx_eq(Position::new(20.0, 0.0), 1e-12));
+///     assert!(path[5].approx_eq(Position::new(80.0, 0.0), 1e-12));
+///
+///     let tab = RoundedRectangle::new(CornerRadii::new(8.0, 8.0, 0.0, 0.0), 2);
+///     let path = tab.path_in(rect);
+///     assert_eq!(path.len(), 2 * 3 + 2);
+///     assert_eq!(
+///         &path[6..],
+///         &[Position::new(100.0, 40.0), Position::new(0.0, 40.0)]
+///     );
+///
+///     // From right to left, the leading corner is the top right one.
+///     let badge = RoundedRectangle::new(CornerRadii::new(8.0, 0.0, 0.0, 0.0), 2)
+///         .layout_direction(LayoutDirection::RightToLeft);
+///     let path = badge.path_in(rect);
+///     assert_eq!(path.len(), 3 + 3);
+///     assert_eq!(path[0], Position::new(0.0, 0.0));
+///     assert!(path[1].approx_eq(Position::new(92.0, 0.0), 1e-12));
+///     assert!(path[3].approx_eq(Position::new(100.0, 8.0), 1e-12));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedRectangle {
+    radii: CornerRadii,
+    segments: usize,
+    direction: LayoutDirection,
+}
+
+impl RoundedRectangle {
+    /// Creates a rectangle rounded by the `radii`, approximating each corner with `segments`
+    /// segments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is 0.
+    pub fn new(radii: CornerRadii, segments: usize) -> Self {
+        assert!(segments != 0, "corner segment count must be non zero");
+        Self {
+            radii,
+            segments,
+            direction: LayoutDirection::default(),
+        }
+    }
+
+    /// Creates a rectangle rounded by the same `radius` on every corner, approximating each
+    /// corner with `segments` segments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is 0.
+    pub fn uniform(radius: f64, segments: usize) -> Self {
+        Self::new(CornerRadii::all(radius), segments)
+    }
+
+    /// The radius of each corner, before clamping.
+    pub fn radii(&self) -> CornerRadii {
+        self.radii
+    }
+
+    /// The number of segments approximating each corner.
+    pub fn segments(&self) -> usize {
+        self.segments
+    }
+
+    /// Resolves the leading and trailing corners under the layout `direction`.
+    pub fn layout_direction(mut self, direction: LayoutDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Appends the vertices of a corner arc around `center`, starting at the angle `start`.
+    fn push_corner(&self, path: &mut Vec<Position>, center: Position, radius: f64, start: f64) {
+        if radius == 0.0 {
+            path.push(center);
+            return;
+        }
+
+        path.extend((0..=self.segments).map(|segment| {
+            let angle = start + FRAC_PI_2 * segment as f64 / self.segments as f64;
+            let (sin, cos) = angle.sin_cos();
+            Position::new(center.x() + radius * cos, center.y() + radius * sin)
+        }));
+    }
+}
+
+impl Shape for RoundedRectangle {
+    /// The outline clockwise, starting from the top left corner.
+    fn path_in(&self, rect: Rect) -> Vec<Position> {
+        let radii = self.radii.resolve(self.direction);
+        let limit = rect.size().width().min(rect.size().height()) / 2.0;
+        let clamp = |radius: f64| radius.clamp(0.0, limit.max(0.0));
+        let (min_x, min_y, max_x, max_y) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+
+        let corners = [
+            (radii.top_left, min_x, min_y, 1.0, 1.0, PI),
+            (radii.top_right, max_x, min_y, -1.0, 1.0, PI + FRAC_PI_2),
+            (radii.bottom_right, max_x, max_y, -1.0, -1.0, 0.0),
+            (radii.bottom_left, min_x, max_y, 1.0, -1.0, FRAC_PI_2),
+        ];
+        let mut path = Vec::with_capacity(4 * (self.segments + 1));
+        for (radius, x, y, inward_x, inward_y, start) in corners {
+            let radius = clamp(radius);
+            let center = Position::new(x + inward_x * radius, y + inward_y * radius);
+            self.push_corner(&mut path, center, radius, start);
+        }
+        path
+    }
+}
@@ -0,0 +1,107 @@
+//! [`Size`](crate::layout::Size) definition.
+
+use super::*;
+use std::simd::f64x2;
+
+/// The dimensions of a two dimensional area.
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::Size;
+///
+/// fn main() {
+///     let size = Size::new(4.0, 3.0);
+///     println!("{size:?}");
+/// # assert_eq!(size.width(), 4.0);
+/// # assert_eq!(size.height(), 3.0);
+/// # assert_eq!(Size::from((4.0, 3.0)), size);
+/// # assert_eq!(Size::from((0.0, 0.0)), Size::zero());
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Size(f64x2);
+
+impl Size {
+    /// Creates a new size from its dimensions.
+    pub fn new(width: f64, height: f64) -> Self {
+        Self(f64x2::from_array([width, height]))
+    }
+
+    /// The empty size.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// The horizontal dimension.
+    pub fn width(&self) -> f64 {
+        self.0[0]
+    }
+
+    /// The vertical dimension.
+    pub fn height(&self) -> f64 {
+        self.0[1]
+    }
+
+    /// The dimension along the `axis`.
+    pub fn main(&self, axis: Axis) -> f64 {
+        self.0[axis.index()]
+    }
+
+    /// The dimension perpendicular to the `axis`.
+    pub fn cross(&self, axis: Axis) -> f64 {
+        self.main(axis.perpendicular())
+    }
+
+    /// The area covered by the size.
+    ///
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::Size;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Size::new(4.0, 3.0).area(), 12.0);
+    ///     assert_eq!(Size::zero().area(), 0.0);
+    /// }
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    /// The ratio of the width to the height.
+    ///
+    /// Returns `None` when the height is zero.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::Size;
+    ///
+    /// fn main() {
+    ///     assert_eq!(Size::new(4.0, 2.0).aspect_ratio(), Some(2.0));
+    ///     assert_eq!(Size::new(2.0, 4.0).aspect_ratio(), Some(0.5));
+    ///     assert_eq!(Size::new(3.0, 3.0).aspect_ratio(), Some(1.0));
+    ///     assert_eq!(Size::zero().aspect_ratio(), None);
+    /// }
+    /// ```
+    pub fn aspect_ratio(&self) -> Option<f64> {
+        (self.height() != 0.0).then(|| self.width() / self.height())
+    }
+
+    /// Creates a size from its dimensions along and perpendicular to the `axis`.
+    pub(crate) fn from_axis(axis: Axis, main: f64, cross: f64) -> Self {
+        Self(f64x2::from_array(axis.components(main, cross)))
+    }
+}
+
+impl From<(f64, f64)> for Size {
+    fn from((width, height): (f64, f64)) -> Self {
+        Self::new(width, height)
+    }
+}
+
+impl Debug for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Size")
+            .field("width", &self.width())
+            .field("height", &self.height())
+            .finish()
+    }
+}
@@ -0,0 +1,60 @@
+//! Pixel grid snapping helpers.
+
+use super::*;
+
+/// Rounds a coordinate to the closest line of a pixel grid of the given `scale`.
+fn snap(coordinate: f64, scale: f64) -> f64 {
+    (coordinate * scale).round() / scale
+}
+
+/// Snaps rectangles to the pixel grid of a display with `scale` pixels per point.
+///
+/// Each edge of a rectangle is rounded to the closest grid line rather than its origin and size,
+/// so edges shared by abutting rectangles land on the same grid line: adjacent rectangles neither
+/// overlap nor leave gaps, the rounding error being absorbed by their sizes.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::{
+///     snap::snap_rects,
+///     Position,
+///     Rect,
+///     Size,
+/// };
+///
+/// fn main() {
+///     let mut rects: Vec<_> = (0..3)
+///         .map(|index| {
+///             Rect::new(
+///                 Position::new(0.3 + 10.4 * index as f64, 0.2),
+///                 Size::new(10.4, 5.6),
+///             )
+///         })
+///         .collect();
+///     snap_rects(&mut rects, 2.0);
+///
+///     for pair in rects.windows(2) {
+///         let end = pair[0].origin().x() + pair[0].size().width();
+///         assert_eq!(end, pair[1].origin().x());
+///     }
+///     for rect in &rects {
+///         for edge in [rect.origin().x(), rect.origin().y(), rect.size().width()] {
+///             assert_eq!(edge * 2.0, (edge * 2.0).round());
+///         }
+///     }
+///     assert_eq!(
+///         rects[0],
+///         Rect::new(Position::new(0.5, 0.0), Size::new(10.0, 6.0))
+///     );
+/// }
+/// ```
+pub fn snap_rects(rects: &mut [Rect], scale: f64) {
+    for rect in rects {
+        let (origin, size) = (rect.origin(), rect.size());
+        let min = Position::new(snap(origin.x(), scale), snap(origin.y(), scale));
+        let max = Position::new(
+            snap(origin.x() + size.width(), scale),
+            snap(origin.y() + size.height(), scale),
+        );
+        *rect = Rect::new(min, Size::new(max.x() - min.x(), max.y() - min.y()));
+    }
+}
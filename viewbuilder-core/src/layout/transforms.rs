@@ -0,0 +1,246 @@
+//! [`layout`](crate::layout) module transforms.
+
+use super::*;
+use crate::transform::{
+    InversibleTransform,
+    MutableTransform,
+    OnceTransform,
+    Transform,
+};
+use std::simd::f64x2;
+
+/// A transform translating a position by a delta.
+///
+/// The inverse of a translate is a translate by the negated delta.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     let position = Position::new(1.0, 2.0);
+///     let translate = Translate(Position::new(10.0, -5.0));
+///     let translated = translate.apply(position);
+///     let restored = translate.inverse().apply(translated);
+///     println!("{translated:?} {restored:?}");
+/// # assert_eq!(translated, Position::new(11.0, -3.0));
+/// # assert_eq!(restored, position);
+/// # assert_eq!(translate.revert(translated), position);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Translate(pub Position);
+
+impl OnceTransform<Position> for Translate {
+    type Output = Position;
+
+    fn apply_once(self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Position> for Translate {
+    fn apply_mut(&mut self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Position> for Translate {
+    fn apply(&self, input: Position) -> Self::Output {
+        let Self(delta) = self;
+        input + *delta
+    }
+}
+
+impl InversibleTransform<Position> for Translate {
+    type Inverse = Translate;
+
+    fn inverse(&self) -> Self::Inverse {
+        let Self(delta) = self;
+        Translate(-*delta)
+    }
+}
+
+/// A transform scaling a position by a factor along each axis, about the origin.
+///
+/// The inverse of a scale is a scale by the reciprocal factors. Scaling by zero cannot be undone,
+/// so zero factors are rejected on creation.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     let position = Position::new(1.0, -2.0);
+///     let doubled = Scale::uniform(2.0).apply(position);
+///     println!("{doubled:?}");
+/// # assert_eq!(doubled, Position::new(2.0, -4.0));
+///
+///     let stretch = Scale::non_uniform(4.0, 0.5);
+///     let stretched = stretch.apply(position);
+///     println!("{stretched:?}");
+/// # assert_eq!(stretched, Position::new(4.0, -1.0));
+/// # assert_eq!(stretch.inverse().apply(stretched), position);
+/// # assert_eq!((stretch.x(), stretch.y()), (4.0, 0.5));
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct Scale(f64x2);
+
+impl Scale {
+    /// Creates a scale by the same `factor` along both axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero.
+    pub fn uniform(factor: f64) -> Self {
+        Self::non_uniform(factor, factor)
+    }
+
+    /// Creates a scale by `x` horizontally and `y` vertically.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either factor is zero.
+    pub fn non_uniform(x: f64, y: f64) -> Self {
+        assert!(x != 0.0 && y != 0.0, "scale factors must be non-zero");
+        Self(f64x2::from_array([x, y]))
+    }
+
+    /// The horizontal factor.
+    pub fn x(&self) -> f64 {
+        self.0[0]
+    }
+
+    /// The vertical factor.
+    pub fn y(&self) -> f64 {
+        self.0[1]
+    }
+}
+
+impl Debug for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scale")
+            .field("x", &self.x())
+            .field("y", &self.y())
+            .finish()
+    }
+}
+
+impl OnceTransform<Position> for Scale {
+    type Output = Position;
+
+    fn apply_once(self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Position> for Scale {
+    fn apply_mut(&mut self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Position> for Scale {
+    fn apply(&self, input: Position) -> Self::Output {
+        let [x, y] = (f64x2::from_array([input.x(), input.y()]) * self.0).to_array();
+        Position::new(x, y)
+    }
+}
+
+impl InversibleTransform<Position> for Scale {
+    type Inverse = Scale;
+
+    fn inverse(&self) -> Self::Inverse {
+        Self(f64x2::splat(1.0) / self.0)
+    }
+}
+
+/// A transform rotating a position about the origin.
+///
+/// Positive angles turn the horizontal axis towards the vertical one, which appears clockwise as
+/// vertical coordinates grow downwards. The inverse of a rotate is a rotate by the negated angle.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     let quarter_turn = Rotate::degrees(90.0);
+///     let rotated = quarter_turn.apply(Position::new(1.0, 0.0));
+///     println!("{rotated:?}");
+/// # let close = |a: Position, b: Position| {
+/// #     (a.x() - b.x()).abs() < 1e-12 && (a.y() - b.y()).abs() < 1e-12
+/// # };
+/// # assert!(close(rotated, Position::new(0.0, 1.0)));
+/// # assert!(close(quarter_turn.inverse().apply(rotated), Position::new(1.0, 0.0)));
+/// # assert!(close(
+/// #     Rotate::radians(std::f64::consts::PI).apply(Position::new(1.0, 2.0)),
+/// #     Position::new(-1.0, -2.0),
+/// # ));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rotate {
+    angle: Angle,
+}
+
+impl Rotate {
+    /// Creates a rotation by an `angle`.
+    pub fn new(angle: Angle) -> Self {
+        Self { angle }
+    }
+
+    /// Creates a rotation by an angle measured in radians.
+    pub fn radians(radians: f64) -> Self {
+        Self::new(Angle::from_radians(radians))
+    }
+
+    /// Creates a rotation by an angle measured in degrees.
+    pub fn degrees(degrees: f64) -> Self {
+        Self::new(Angle::from_degrees(degrees))
+    }
+
+    /// The angle of the rotation.
+    pub fn angle(&self) -> Angle {
+        self.angle
+    }
+}
+
+impl OnceTransform<Position> for Rotate {
+    type Output = Position;
+
+    fn apply_once(self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Position> for Rotate {
+    fn apply_mut(&mut self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Position> for Rotate {
+    fn apply(&self, input: Position) -> Self::Output {
+        let (sin, cos) = self.angle.radians().sin_cos();
+        Position::new(
+            input.x() * cos - input.y() * sin,
+            input.x() * sin + input.y() * cos,
+        )
+    }
+}
+
+impl InversibleTransform<Position> for Rotate {
+    type Inverse = Rotate;
+
+    fn inverse(&self) -> Self::Inverse {
+        Self::new(-self.angle)
+    }
+}
@@ -0,0 +1,103 @@
+//! [`Vector`](crate::layout::Vector) definition.
+
+use super::*;
+use std::{
+    f64::consts::{
+        PI,
+        TAU,
+    },
+    simd::f64x2,
+};
+
+/// A displacement in a two dimensional space.
+///
+/// Where a [`Position`] designates a point, a vector designates a direction and a magnitude.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::layout::Vector;
+///
+/// fn main() {
+///     let vector = Vector::new(3.0, 4.0);
+///     println!("{vector:?}");
+/// # assert_eq!(vector.x(), 3.0);
+/// # assert_eq!(vector.y(), 4.0);
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Vector(f64x2);
+
+impl Vector {
+    /// Creates a new vector from its components.
+    pub fn new(x: f64, y: f64) -> Self {
+        Self(f64x2::from_array([x, y]))
+    }
+
+    /// The null vector.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// The horizontal component.
+    pub fn x(&self) -> f64 {
+        self.0[0]
+    }
+
+    /// The vertical component.
+    pub fn y(&self) -> f64 {
+        self.0[1]
+    }
+
+    /// The magnitude of the vector.
+    pub fn length(&self) -> f64 {
+        self.x().hypot(self.y())
+    }
+
+    /// Interpolates between the vector and `other`, rotating rather than moving in a straight
+    /// line.
+    ///
+    /// The direction is interpolated along the shorter arc between both vectors and the magnitude
+    /// linearly, `t` being `0.0` at the vector and `1.0` at `other`. As a null vector has no
+    /// direction, the components are interpolated linearly when either vector is null.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::layout::Vector;
+    ///
+    /// fn main() {
+    ///     let halfway = Vector::new(1.0, 0.0).rotational_lerp(Vector::new(0.0, 1.0), 0.5);
+    ///     let diagonal = std::f64::consts::FRAC_1_SQRT_2;
+    ///     assert!((halfway.x() - diagonal).abs() < 1e-12);
+    ///     assert!((halfway.y() - diagonal).abs() < 1e-12);
+    ///
+    ///     let shorter_arc = Vector::new(0.0, -1.0).rotational_lerp(Vector::new(-1.0, 0.0), 0.5);
+    ///     assert!((shorter_arc.x() + diagonal).abs() < 1e-12);
+    ///     assert!((shorter_arc.y() + diagonal).abs() < 1e-12);
+    ///
+    ///     let from_zero = Vector::zero().rotational_lerp(Vector::new(2.0, 4.0), 0.5);
+    ///     assert_eq!(from_zero, Vector::new(1.0, 2.0));
+    /// }
+    /// ```
+    pub fn rotational_lerp(&self, other: Vector, t: f64) -> Vector {
+        let (from, to) = (self.length(), other.length());
+        if from == 0.0 || to == 0.0 {
+            return Vector::new(
+                self.x() + (other.x() - self.x()) * t,
+                self.y() + (other.y() - self.y()) * t,
+            );
+        }
+
+        let start = self.y().atan2(self.x());
+        let arc = (other.y().atan2(other.x()) - start + PI).rem_euclid(TAU) - PI;
+        let (sin, cos) = (start + arc * t).sin_cos();
+        let length = from + (to - from) * t;
+        Vector::new(length * cos, length * sin)
+    }
+}
+
+impl Debug for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Vector")
+            .field("x", &self.x())
+            .field("y", &self.y())
+            .finish()
+    }
+}
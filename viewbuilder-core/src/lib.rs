@@ -6,7 +6,13 @@
     rust_2018_idioms,
     unreachable_pub
 )]
-#![feature(never_type, format_args_capture, trait_alias, external_doc)]
+#![feature(
+    never_type,
+    format_args_capture,
+    trait_alias,
+    external_doc,
+    portable_simd
+)]
 #![doc(
     test(
         no_crate_inject,
@@ -17,6 +23,8 @@
     include = "../README.md"
 )]
 
+pub mod color;
+pub mod layout;
 pub mod prelude;
 pub mod transform;
 pub mod view;
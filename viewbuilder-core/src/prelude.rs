@@ -38,6 +38,11 @@ pub(crate) use std::{
     str::FromStr,
 };
 
+pub use crate::{
+    transform::TransformExt,
+    view::ViewExt,
+};
+
 /// An alias for the `()` type. Used to get a more uniform syntax.
 pub type Unit = ();
 
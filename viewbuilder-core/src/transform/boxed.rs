@@ -0,0 +1,82 @@
+//! [`BoxedTransform`](crate::transform::BoxedTransform) definition.
+
+use super::*;
+
+/// A type-erased transform from `Input` to `Output`.
+///
+/// Erasing the type lets transforms of different types be stored together, at the cost of a heap
+/// allocation when boxing and of a dynamic dispatch on every application, which also prevents the
+/// compiler from inlining the boxed transform.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let transforms: Vec<BoxedTransform<i32, i32>> =
+///         vec![Add(1).boxed(), Mul(2).boxed(), from_fn(|x: i32| -x).boxed()];
+///     let outputs: Vec<_> = transforms
+///         .iter()
+///         .map(|transform| transform.apply(3))
+///         .collect();
+///     println!("{outputs:?}");
+/// # assert_eq!(outputs, vec![4, 6, -3]);
+/// # assert_eq!(Pipeline::from(transforms).apply(3), -8);
+/// }
+/// ```
+pub struct BoxedTransform<Input, Output> {
+    transform: Box<dyn Transform<Input, Output = Output> + Send + Sync>,
+}
+
+impl<Input, Output> BoxedTransform<Input, Output>
+where
+    Input: SendSync,
+    Output: SendSync,
+{
+    /// Erases the type of `transform`.
+    pub fn new<TransformType>(transform: TransformType) -> Self
+    where
+        TransformType: Transform<Input, Output = Output> + 'static,
+    {
+        Self {
+            transform: Box::new(transform),
+        }
+    }
+}
+
+impl<Input, Output> Debug for BoxedTransform<Input, Output> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedTransform").finish_non_exhaustive()
+    }
+}
+
+impl<Input, Output> OnceTransform<Input> for BoxedTransform<Input, Output>
+where
+    Input: SendSync,
+    Output: SendSync,
+{
+    type Output = Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Output> MutableTransform<Input> for BoxedTransform<Input, Output>
+where
+    Input: SendSync,
+    Output: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Output> Transform<Input> for BoxedTransform<Input, Output>
+where
+    Input: SendSync,
+    Output: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.transform.apply(input)
+    }
+}
@@ -0,0 +1,406 @@
+//! Transform operations over collections.
+
+use super::*;
+use std::{
+    collections::HashMap,
+    hash::Hash,
+};
+
+/// A transform selecting the first element of a collection.
+///
+/// Without a predicate the first element yielded by the input is selected. A predicate can be
+/// given with [`First::matching`] to select the first element for which it returns `true`.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let first = First::new();
+///     assert_eq!(first.apply(vec![1, 2, 3, 4]), Some(1));
+///
+///     let first_even = First::matching(|value: &i32| value % 2 == 0);
+///     assert_eq!(first_even.apply(vec![1, 2, 3, 4]), Some(2));
+///     assert_eq!(first_even.apply(vec![1, 3, 5]), None);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct First<Predicate = Unit> {
+    predicate: Predicate,
+}
+
+impl First {
+    /// Creates a transform selecting the first element.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<Predicate> First<Predicate> {
+    /// Creates a transform selecting the first element matching the `predicate`.
+    pub fn matching(predicate: Predicate) -> Self {
+        Self { predicate }
+    }
+}
+
+impl<Input, Item> OnceTransform<Input> for First
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    type Output = Option<Item>;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item> MutableTransform<Input> for First
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item> Transform<Input> for First
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        input.into_iter().next()
+    }
+}
+
+impl<Input, Item, Predicate> OnceTransform<Input> for First<Predicate>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+    Predicate: Fn(&Item) -> bool + SendSync,
+{
+    type Output = Option<Item>;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item, Predicate> MutableTransform<Input> for First<Predicate>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+    Predicate: Fn(&Item) -> bool + SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item, Predicate> Transform<Input> for First<Predicate>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+    Predicate: Fn(&Item) -> bool + SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        input.into_iter().find(|item| (self.predicate)(item))
+    }
+}
+
+/// A transform selecting the last element of a collection.
+///
+/// Without a predicate the last element yielded by the input is selected. A predicate can be
+/// given with [`Last::matching`] to select the last element for which it returns `true`.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let last = Last::new();
+///     assert_eq!(last.apply(vec![1, 2, 3, 4]), Some(4));
+///
+///     let last_odd = Last::matching(|value: &i32| value % 2 != 0);
+///     assert_eq!(last_odd.apply(vec![1, 2, 3, 4]), Some(3));
+///     assert_eq!(last_odd.apply(Vec::new()), None);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Last<Predicate = Unit> {
+    predicate: Predicate,
+}
+
+impl Last {
+    /// Creates a transform selecting the last element.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<Predicate> Last<Predicate> {
+    /// Creates a transform selecting the last element matching the `predicate`.
+    pub fn matching(predicate: Predicate) -> Self {
+        Self { predicate }
+    }
+}
+
+impl<Input, Item> OnceTransform<Input> for Last
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    type Output = Option<Item>;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item> MutableTransform<Input> for Last
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item> Transform<Input> for Last
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        input.into_iter().last()
+    }
+}
+
+impl<Input, Item, Predicate> OnceTransform<Input> for Last<Predicate>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+    Predicate: Fn(&Item) -> bool + SendSync,
+{
+    type Output = Option<Item>;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item, Predicate> MutableTransform<Input> for Last<Predicate>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+    Predicate: Fn(&Item) -> bool + SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item, Predicate> Transform<Input> for Last<Predicate>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+    Predicate: Fn(&Item) -> bool + SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        input.into_iter().fold(None, |last, item| {
+            if (self.predicate)(&item) {
+                Some(item)
+            } else {
+                last
+            }
+        })
+    }
+}
+
+/// A transform removing consecutive duplicates from a collection.
+///
+/// Only adjacent repetitions are removed, a value reappearing later in the sequence is kept.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let values = DedupAdjacent.apply(vec![1, 1, 2, 3, 3, 3, 1]);
+///     println!("{values:?}");
+/// # assert_eq!(values, vec![1, 2, 3, 1]);
+/// # assert_eq!(DedupAdjacent.apply(Vec::<i32>::new()), Vec::new());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DedupAdjacent;
+
+impl<Item> OnceTransform<Vec<Item>> for DedupAdjacent
+where
+    Item: PartialEq + SendSync,
+{
+    type Output = Vec<Item>;
+
+    fn apply_once(self, input: Vec<Item>) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Item> MutableTransform<Vec<Item>> for DedupAdjacent
+where
+    Item: PartialEq + SendSync,
+{
+    fn apply_mut(&mut self, input: Vec<Item>) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Item> Transform<Vec<Item>> for DedupAdjacent
+where
+    Item: PartialEq + SendSync,
+{
+    fn apply(&self, mut input: Vec<Item>) -> Self::Output {
+        input.dedup();
+        input
+    }
+}
+
+/// A transform combining each pair of adjacent elements of a collection.
+///
+/// The combinator is given the previous and current elements of each pair, producing one output
+/// less than there are elements.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let deltas = Pairwise::new(|previous: &i32, current: &i32| current - previous);
+///     let outputs = deltas.apply(vec![0, 3, 7]);
+///     println!("{outputs:?}");
+/// # assert_eq!(outputs, vec![3, 4]);
+/// # assert_eq!(deltas.apply(vec![1]), Vec::new());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Pairwise<Combine> {
+    combine: Combine,
+}
+
+impl<Combine> Pairwise<Combine> {
+    /// Creates a transform combining adjacent elements with `combine`.
+    pub fn new(combine: Combine) -> Self {
+        Self { combine }
+    }
+}
+
+impl<Input, Item, Combine, Output> OnceTransform<Input> for Pairwise<Combine>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Combine: Fn(&Item, &Item) -> Output + SendSync,
+    Output: SendSync,
+{
+    type Output = Vec<Output>;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item, Combine, Output> MutableTransform<Input> for Pairwise<Combine>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Combine: Fn(&Item, &Item) -> Output + SendSync,
+    Output: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Item, Combine, Output> Transform<Input> for Pairwise<Combine>
+where
+    Input: IntoIterator<Item = Item> + SendSync,
+    Combine: Fn(&Item, &Item) -> Output + SendSync,
+    Output: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        let mut items = input.into_iter();
+        let Some(mut previous) = items.next() else {
+            return Vec::new();
+        };
+        items
+            .map(|current| {
+                let output = (self.combine)(&previous, &current);
+                previous = current;
+                output
+            })
+            .collect()
+    }
+}
+
+/// A transform grouping the elements of a collection by a key.
+///
+/// Elements keep their relative order within their group.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let by_parity = GroupBy::new(|value: &i32| value % 2 == 0);
+///     let groups = by_parity.apply(vec![1, 2, 3, 4]);
+///     println!("{groups:?}");
+/// # assert_eq!(groups.len(), 2);
+/// # assert_eq!(groups[&false], vec![1, 3]);
+/// # assert_eq!(groups[&true], vec![2, 4]);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GroupBy<KeyFn> {
+    key: KeyFn,
+}
+
+impl<KeyFn> GroupBy<KeyFn> {
+    /// Creates a transform grouping elements by the result of `key`.
+    pub fn new(key: KeyFn) -> Self {
+        Self { key }
+    }
+}
+
+impl<Item, Key, KeyFn> OnceTransform<Vec<Item>> for GroupBy<KeyFn>
+where
+    Item: SendSync,
+    Key: Eq + Hash + SendSync,
+    KeyFn: Fn(&Item) -> Key + SendSync,
+{
+    type Output = HashMap<Key, Vec<Item>>;
+
+    fn apply_once(self, input: Vec<Item>) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Item, Key, KeyFn> MutableTransform<Vec<Item>> for GroupBy<KeyFn>
+where
+    Item: SendSync,
+    Key: Eq + Hash + SendSync,
+    KeyFn: Fn(&Item) -> Key + SendSync,
+{
+    fn apply_mut(&mut self, input: Vec<Item>) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Item, Key, KeyFn> Transform<Vec<Item>> for GroupBy<KeyFn>
+where
+    Item: SendSync,
+    Key: Eq + Hash + SendSync,
+    KeyFn: Fn(&Item) -> Key + SendSync,
+{
+    fn apply(&self, input: Vec<Item>) -> Self::Output {
+        let mut groups: HashMap<Key, Vec<Item>> = HashMap::new();
+        for item in input {
+            groups.entry((self.key)(&item)).or_default().push(item);
+        }
+        groups
+    }
+}
@@ -0,0 +1,1182 @@
+//! Transform operations combining other transforms.
+
+use super::*;
+use std::{
+    collections::VecDeque,
+    marker::PhantomData,
+    ops::ControlFlow,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Mutex,
+        OnceLock,
+        PoisonError,
+    },
+};
+
+/// A transform applying several transforms to the same input and collecting their outputs.
+///
+/// Each branch receives its own clone of the input.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let branches = Branches::new(vec![Add(1), Add(2), Add(3)]);
+///     let outputs = branches.apply(0);
+///     println!("{outputs:?}");
+/// # assert_eq!(outputs, vec![1, 2, 3]);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Branches<TransformType> {
+    branches: Vec<TransformType>,
+}
+
+impl<TransformType> Branches<TransformType> {
+    /// Creates a transform applying each of the `branches`.
+    pub fn new(branches: Vec<TransformType>) -> Self {
+        Self { branches }
+    }
+}
+
+impl<Input, TransformType> OnceTransform<Input> for Branches<TransformType>
+where
+    TransformType: OnceTransform<Input>,
+    Input: Clone + SendSync,
+{
+    type Output = Vec<TransformType::Output>;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.branches
+            .into_iter()
+            .map(|branch| branch.apply_once(input.clone()))
+            .collect()
+    }
+}
+
+impl<Input, TransformType> MutableTransform<Input> for Branches<TransformType>
+where
+    TransformType: MutableTransform<Input>,
+    Input: Clone + SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.branches
+            .iter_mut()
+            .map(|branch| branch.apply_mut(input.clone()))
+            .collect()
+    }
+}
+
+impl<Input, TransformType> Transform<Input> for Branches<TransformType>
+where
+    TransformType: Transform<Input>,
+    Input: Clone + SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.branches
+            .iter()
+            .map(|branch| branch.apply(input.clone()))
+            .collect()
+    }
+}
+
+/// Splits `input` in chunks of `size` elements, the last one holding the remainder.
+fn chunks<Input, Item>(input: Input, size: usize) -> impl Iterator<Item = Vec<Item>>
+where
+    Input: IntoIterator<Item = Item>,
+{
+    let mut items = input.into_iter().peekable();
+    std::iter::from_fn(move || {
+        items.peek()?;
+        Some(items.by_ref().take(size).collect())
+    })
+}
+
+/// A transform splitting its input in fixed-size chunks and applying a transform to each of them.
+///
+/// The last chunk holds the remaining elements and may be shorter than the configured size.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug)]
+/// struct Sum;
+///
+/// impl OnceTransform<Vec<i32>> for Sum {
+///     type Output = (usize, i32);
+///
+///     fn apply_once(self, input: Vec<i32>) -> Self::Output {
+///         self.apply(input)
+///     }
+/// }
+///
+/// impl MutableTransform<Vec<i32>> for Sum {
+///     fn apply_mut(&mut self, input: Vec<i32>) -> Self::Output {
+///         self.apply(input)
+///     }
+/// }
+///
+/// impl Transform<Vec<i32>> for Sum {
+///     fn apply(&self, input: Vec<i32>) -> Self::Output {
+///         (input.len(), input.iter().sum())
+///     }
+/// }
+///
+/// fn main() {
+///     let chunked = Chunked::new(Sum, 3);
+///     let outputs = chunked.apply(0..7);
+///     println!("{outputs:?}");
+/// # assert_eq!(outputs, vec![(3, 3), (3, 12), (1, 6)]);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chunked<TransformType> {
+    transform: TransformType,
+    size: usize,
+}
+
+impl<TransformType> Chunked<TransformType> {
+    /// Creates a transform applying `transform` to chunks of `size` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn new(transform: TransformType, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non zero");
+        Self { transform, size }
+    }
+
+    /// The number of elements in a chunk.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl<Input, Item, TransformType> OnceTransform<Input> for Chunked<TransformType>
+where
+    TransformType: MutableTransform<Vec<Item>>,
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    type Output = Vec<TransformType::Output>;
+
+    fn apply_once(mut self, input: Input) -> Self::Output {
+        self.apply_mut(input)
+    }
+}
+
+impl<Input, Item, TransformType> MutableTransform<Input> for Chunked<TransformType>
+where
+    TransformType: MutableTransform<Vec<Item>>,
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        chunks(input, self.size)
+            .map(|chunk| self.transform.apply_mut(chunk))
+            .collect()
+    }
+}
+
+impl<Input, Item, TransformType> Transform<Input> for Chunked<TransformType>
+where
+    TransformType: Transform<Vec<Item>>,
+    Input: IntoIterator<Item = Item> + SendSync,
+    Item: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        chunks(input, self.size)
+            .map(|chunk| self.transform.apply(chunk))
+            .collect()
+    }
+}
+
+/// Marks a transform yielding a [`ControlFlow`] as a short-circuiting pipeline stage.
+///
+/// Stages chained with [`then_if_continue`](ShortCircuit::then_if_continue) only run when the
+/// previous stage continues, a break skips them and is propagated as the pipeline output.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::{
+///     ops::ControlFlow,
+///     sync::atomic::{
+///         AtomicUsize,
+///         Ordering,
+///     },
+/// };
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug)]
+/// struct NonNegative;
+///
+/// impl OnceTransform<i32> for NonNegative {
+///     type Output = ControlFlow<&'static str, i32>;
+///
+///     fn apply_once(self, input: i32) -> Self::Output {
+///         self.apply(input)
+///     }
+/// }
+///
+/// impl MutableTransform<i32> for NonNegative {
+///     fn apply_mut(&mut self, input: i32) -> Self::Output {
+///         self.apply(input)
+///     }
+/// }
+///
+/// impl Transform<i32> for NonNegative {
+///     fn apply(&self, input: i32) -> Self::Output {
+///         if input < 0 {
+///             ControlFlow::Break("negative input")
+///         } else {
+///             ControlFlow::Continue(input)
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let calls = AtomicUsize::new(0);
+///     let double = from_fn(|x: i32| {
+///         calls.fetch_add(1, Ordering::SeqCst);
+///         x * 2
+///     });
+///     let pipeline = ShortCircuit::new(NonNegative).then_if_continue(&double);
+///
+///     assert_eq!(pipeline.apply(21), ControlFlow::Continue(42));
+///     assert_eq!(pipeline.apply(-1), ControlFlow::Break("negative input"));
+///     assert_eq!(calls.load(Ordering::SeqCst), 1);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShortCircuit<TransformType> {
+    transform: TransformType,
+}
+
+impl<TransformType> ShortCircuit<TransformType> {
+    /// Marks `transform` as a short-circuiting stage.
+    pub fn new(transform: TransformType) -> Self {
+        Self { transform }
+    }
+
+    /// Chains a stage only applied to the continue value of this one.
+    pub fn then_if_continue<Next>(
+        self,
+        next: Next,
+    ) -> ShortCircuit<ThenIfContinue<TransformType, Next>> {
+        ShortCircuit::new(ThenIfContinue {
+            first: self.transform,
+            next,
+        })
+    }
+}
+
+impl<Input, Break, Continue, TransformType> OnceTransform<Input> for ShortCircuit<TransformType>
+where
+    TransformType: OnceTransform<Input, Output = ControlFlow<Break, Continue>>,
+    Input: SendSync,
+    Break: SendSync,
+    Continue: SendSync,
+{
+    type Output = TransformType::Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.transform.apply_once(input)
+    }
+}
+
+impl<Input, Break, Continue, TransformType> MutableTransform<Input> for ShortCircuit<TransformType>
+where
+    TransformType: MutableTransform<Input, Output = ControlFlow<Break, Continue>>,
+    Input: SendSync,
+    Break: SendSync,
+    Continue: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.transform.apply_mut(input)
+    }
+}
+
+impl<Input, Break, Continue, TransformType> Transform<Input> for ShortCircuit<TransformType>
+where
+    TransformType: Transform<Input, Output = ControlFlow<Break, Continue>>,
+    Input: SendSync,
+    Break: SendSync,
+    Continue: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.transform.apply(input)
+    }
+}
+
+/// A short-circuiting stage followed by a stage applied to its continue value.
+///
+/// Created by [`ShortCircuit::then_if_continue`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThenIfContinue<First, Next> {
+    first: First,
+    next: Next,
+}
+
+impl<Input, Break, Continue, First, Next> OnceTransform<Input> for ThenIfContinue<First, Next>
+where
+    First: OnceTransform<Input, Output = ControlFlow<Break, Continue>>,
+    Next: OnceTransform<Continue>,
+    Input: SendSync,
+    Break: SendSync,
+    Continue: SendSync,
+{
+    type Output = ControlFlow<Break, Next::Output>;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        match self.first.apply_once(input) {
+            ControlFlow::Continue(value) => ControlFlow::Continue(self.next.apply_once(value)),
+            ControlFlow::Break(value) => ControlFlow::Break(value),
+        }
+    }
+}
+
+impl<Input, Break, Continue, First, Next> MutableTransform<Input> for ThenIfContinue<First, Next>
+where
+    First: MutableTransform<Input, Output = ControlFlow<Break, Continue>>,
+    Next: MutableTransform<Continue>,
+    Input: SendSync,
+    Break: SendSync,
+    Continue: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        match self.first.apply_mut(input) {
+            ControlFlow::Continue(value) => ControlFlow::Continue(self.next.apply_mut(value)),
+            ControlFlow::Break(value) => ControlFlow::Break(value),
+        }
+    }
+}
+
+impl<Input, Break, Continue, First, Next> Transform<Input> for ThenIfContinue<First, Next>
+where
+    First: Transform<Input, Output = ControlFlow<Break, Continue>>,
+    Next: Transform<Continue>,
+    Input: SendSync,
+    Break: SendSync,
+    Continue: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        match self.first.apply(input) {
+            ControlFlow::Continue(value) => ControlFlow::Continue(self.next.apply(value)),
+            ControlFlow::Break(value) => ControlFlow::Break(value),
+        }
+    }
+}
+
+/// A transform applying a transform to the output of another.
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let chained = Then::new(Add(1), Add(2));
+///     let output = chained.apply(0);
+///     println!("{output}");
+/// # assert_eq!(output, 3);
+/// # assert_eq!(Then::new(Add(1), Add(2)).apply_once(0), 3);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Then<First, Next> {
+    first: First,
+    next: Next,
+}
+
+impl<First, Next> Then<First, Next> {
+    /// Creates a transform applying `next` to the output of `first`.
+    pub fn new(first: First, next: Next) -> Self {
+        Self { first, next }
+    }
+
+    /// The transform applied first.
+    pub fn first(&self) -> &First {
+        &self.first
+    }
+
+    /// The transform applied to the output of the first one.
+    pub fn next(&self) -> &Next {
+        &self.next
+    }
+
+    /// Splits the chain into its first and next transforms.
+    pub fn into_parts(self) -> (First, Next) {
+        (self.first, self.next)
+    }
+}
+
+impl<Input, First, Next> OnceTransform<Input> for Then<First, Next>
+where
+    First: OnceTransform<Input>,
+    Next: OnceTransform<First::Output>,
+    Input: SendSync,
+{
+    type Output = Next::Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.next.apply_once(self.first.apply_once(input))
+    }
+}
+
+impl<Input, First, Next> MutableTransform<Input> for Then<First, Next>
+where
+    First: MutableTransform<Input>,
+    Next: MutableTransform<First::Output>,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.next.apply_mut(self.first.apply_mut(input))
+    }
+}
+
+impl<Input, First, Next> Transform<Input> for Then<First, Next>
+where
+    First: Transform<Input>,
+    Next: Transform<First::Output>,
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.next.apply(self.first.apply(input))
+    }
+}
+
+/// Merges several iterators into one, taking an element from each of them in turn.
+///
+/// Exhausted iterators are skipped, the merged sequence ending once all of them are exhausted.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::combinators::interleave;
+///
+/// fn main() {
+///     let merged: Vec<_> = interleave(vec![
+///         Box::new(vec![1, 2, 3].into_iter()),
+///         Box::new(vec![4, 5].into_iter()),
+///     ])
+///     .collect();
+///     println!("{merged:?}");
+/// # assert_eq!(merged, vec![1, 4, 2, 5, 3]);
+/// }
+/// ```
+pub fn interleave<Item>(iters: Vec<Box<dyn Iterator<Item = Item>>>) -> impl Iterator<Item = Item> {
+    let mut iters: VecDeque<_> = iters.into();
+    std::iter::from_fn(move || {
+        while let Some(mut iter) = iters.pop_front() {
+            if let Some(item) = iter.next() {
+                iters.push_back(iter);
+                return Some(item);
+            }
+        }
+        None
+    })
+}
+
+/// Advances the xorshift64 `state`, returning the new state.
+fn xorshift(mut state: u64) -> u64 {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+}
+
+/// A transform applying another transform to a random fraction of its inputs.
+///
+/// The inputs which are not sampled pass through unchanged. Sampling is driven by a seeded
+/// pseudo-random generator, so samplers created with the same seed make the same decisions.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let never = Sample::new(Mul(2), 0.0, 42);
+///     assert!((1..=100).all(|input| never.apply(input) == input));
+///
+///     let always = Sample::new(Mul(2), 1.0, 42);
+///     assert!((1..=100).all(|input| always.apply(input) == input * 2));
+///
+///     let first = Sample::new(Mul(2), 0.5, 7);
+///     let second = Sample::new(Mul(2), 0.5, 7);
+///     let outputs: Vec<_> = (1..=100).map(|input| first.apply(input)).collect();
+///     println!("{outputs:?}");
+/// # assert_eq!(outputs, (1..=100).map(|input| second.apply(input)).collect::<Vec<_>>());
+/// # assert!(outputs.iter().zip(1..).any(|(&output, input)| output == input));
+/// # assert!(outputs.iter().zip(1..).any(|(&output, input)| output == input * 2));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Sample<TransformType> {
+    transform: TransformType,
+    rate: f64,
+    state: AtomicU64,
+}
+
+impl<TransformType> Sample<TransformType> {
+    /// Creates a transform applying `transform` to a `rate` fraction of its inputs, sampled by a
+    /// generator initialized with `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not within `[0, 1]`.
+    pub fn new(transform: TransformType, rate: f64, seed: u64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&rate),
+            "sample rate must be within [0, 1]"
+        );
+        // The xorshift generator never leaves the zero state.
+        let state = if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        };
+        Self {
+            transform,
+            rate,
+            state: AtomicU64::new(state),
+        }
+    }
+
+    /// The fraction of inputs the transform is applied to.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Draws whether the next input is sampled.
+    fn sampled(&self) -> bool {
+        let previous = self
+            .state
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |state| {
+                Some(xorshift(state))
+            })
+            .unwrap_or_else(|state| state);
+        let draw = (xorshift(previous) >> 11) as f64 / (1u64 << 53) as f64;
+        draw < self.rate
+    }
+}
+
+impl<TransformType> Clone for Sample<TransformType>
+where
+    TransformType: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            transform: self.transform.clone(),
+            rate: self.rate,
+            state: AtomicU64::new(self.state.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl<Input, TransformType> OnceTransform<Input> for Sample<TransformType>
+where
+    TransformType: OnceTransform<Input, Output = Input>,
+    Input: SendSync,
+{
+    type Output = Input;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        if self.sampled() {
+            self.transform.apply_once(input)
+        } else {
+            input
+        }
+    }
+}
+
+impl<Input, TransformType> MutableTransform<Input> for Sample<TransformType>
+where
+    TransformType: MutableTransform<Input, Output = Input>,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        if self.sampled() {
+            self.transform.apply_mut(input)
+        } else {
+            input
+        }
+    }
+}
+
+impl<Input, TransformType> Transform<Input> for Sample<TransformType>
+where
+    TransformType: Transform<Input, Output = Input>,
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        if self.sampled() {
+            self.transform.apply(input)
+        } else {
+            input
+        }
+    }
+}
+
+/// A side-effecting observer of the inputs of a [`Tee`].
+pub type Sink<Input> = Box<dyn Fn(&Input) + Send + Sync>;
+
+/// A transform handing its input to several sinks before passing it through unchanged.
+///
+/// Sinks are invoked in order, each being given a reference to the input.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::{
+///     Arc,
+///     Mutex,
+/// };
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let logged = Arc::new(Mutex::new(Vec::new()));
+///     let measured = Arc::new(Mutex::new(Vec::new()));
+///     let tee = Tee::new(vec![
+///         Box::new({
+///             let logged = Arc::clone(&logged);
+///             move |input: &i32| logged.lock().unwrap().push(*input)
+///         }),
+///         Box::new({
+///             let measured = Arc::clone(&measured);
+///             move |input: &i32| measured.lock().unwrap().push(input * 10)
+///         }),
+///     ]);
+///
+///     assert_eq!(tee.apply(1), 1);
+///     assert_eq!(tee.apply(2), 2);
+///     assert_eq!(*logged.lock().unwrap(), vec![1, 2]);
+///     assert_eq!(*measured.lock().unwrap(), vec![10, 20]);
+/// }
+/// ```
+pub struct Tee<Input> {
+    sinks: Vec<Sink<Input>>,
+}
+
+impl<Input> Tee<Input> {
+    /// Creates a transform handing its input to each of the `sinks`.
+    pub fn new(sinks: Vec<Sink<Input>>) -> Self {
+        Self { sinks }
+    }
+
+    /// Adds a `sink`, invoked after the existing ones.
+    pub fn with_sink(mut self, sink: impl Fn(&Input) + Send + Sync + 'static) -> Self {
+        self.sinks.push(Box::new(sink));
+        self
+    }
+}
+
+impl<Input> Default for Tee<Input> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<Input> Debug for Tee<Input> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tee")
+            .field("sinks", &self.sinks.len())
+            .finish()
+    }
+}
+
+impl<Input> OnceTransform<Input> for Tee<Input>
+where
+    Input: SendSync,
+{
+    type Output = Input;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input> MutableTransform<Input> for Tee<Input>
+where
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input> Transform<Input> for Tee<Input>
+where
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        for sink in &self.sinks {
+            sink(&input);
+        }
+        input
+    }
+}
+
+/// A transform built on its first application.
+///
+/// The builder runs at most once, the built transform being cached for the following
+/// applications.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::atomic::{
+///     AtomicUsize,
+///     Ordering,
+/// };
+/// use viewbuilder::transform::*;
+///
+/// static BUILDS: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn main() {
+///     let lazy = Lazy::new(|| {
+///         BUILDS.fetch_add(1, Ordering::SeqCst);
+///         Add(10)
+///     });
+///     assert_eq!(BUILDS.load(Ordering::SeqCst), 0);
+///
+///     assert_eq!(lazy.apply(1), 11);
+///     assert_eq!(lazy.apply(2), 12);
+///     assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+/// }
+/// ```
+pub struct Lazy<Builder, TransformType> {
+    builder: Mutex<Option<Builder>>,
+    transform: OnceLock<TransformType>,
+}
+
+impl<Builder, TransformType> Lazy<Builder, TransformType>
+where
+    Builder: FnOnce() -> TransformType,
+{
+    /// Creates a transform built by `builder` on its first application.
+    pub fn new(builder: Builder) -> Self {
+        Self {
+            builder: Mutex::new(Some(builder)),
+            transform: OnceLock::new(),
+        }
+    }
+
+    /// The built transform, building it first if needed.
+    pub fn force(&self) -> &TransformType {
+        self.transform.get_or_init(|| {
+            let builder = self
+                .builder
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take()
+                .expect("lazy transform builder already consumed");
+            builder()
+        })
+    }
+
+    /// Mutable access to the built transform, building it first if needed.
+    fn force_mut(&mut self) -> &mut TransformType {
+        self.force();
+        self.transform
+            .get_mut()
+            .expect("lazy transform built by force")
+    }
+
+    /// Consumes the wrapper into the built transform, building it first if needed.
+    fn into_transform(mut self) -> TransformType {
+        self.force();
+        self.transform
+            .take()
+            .expect("lazy transform built by force")
+    }
+}
+
+impl<Builder, TransformType> Debug for Lazy<Builder, TransformType>
+where
+    TransformType: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lazy")
+            .field("transform", &self.transform.get())
+            .finish()
+    }
+}
+
+impl<Input, Builder, TransformType> OnceTransform<Input> for Lazy<Builder, TransformType>
+where
+    Builder: FnOnce() -> TransformType + Send,
+    TransformType: OnceTransform<Input> + Send + Sync,
+    Input: SendSync,
+{
+    type Output = TransformType::Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.into_transform().apply_once(input)
+    }
+}
+
+impl<Input, Builder, TransformType> MutableTransform<Input> for Lazy<Builder, TransformType>
+where
+    Builder: FnOnce() -> TransformType + Send,
+    TransformType: MutableTransform<Input> + Send + Sync,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.force_mut().apply_mut(input)
+    }
+}
+
+impl<Input, Builder, TransformType> Transform<Input> for Lazy<Builder, TransformType>
+where
+    Builder: FnOnce() -> TransformType + Send,
+    TransformType: Transform<Input> + Send + Sync,
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.force().apply(input)
+    }
+}
+
+/// Makes the calls skipped by an [`EveryNth`] yield their input unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PassThrough;
+
+/// Makes the calls skipped by an [`EveryNth`] yield `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Discard;
+
+/// A transform applying another transform on every `n`th call only.
+///
+/// The skipped calls either pass their input through, which requires the transform to preserve
+/// the input type, or yield `None` when created with [`EveryNth::discarding`].
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let mut every_third = EveryNth::new(Mul(2), 3);
+///     let outputs: Vec<_> = (1..=10).map(|input| every_third.apply_mut(input)).collect();
+///     println!("{outputs:?}");
+/// # assert_eq!(outputs, vec![1, 2, 6, 4, 5, 12, 7, 8, 18, 10]);
+///
+///     let mut every_third = EveryNth::discarding(Mul(2), 3);
+///     let outputs: Vec<_> = (1..=10)
+///         .filter_map(|input| every_third.apply_mut(input))
+///         .collect();
+///     println!("{outputs:?}");
+/// # assert_eq!(outputs, vec![6, 12, 18]);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EveryNth<TransformType, Skipped = PassThrough> {
+    transform: TransformType,
+    n: usize,
+    calls: usize,
+    skipped: Skipped,
+}
+
+impl<TransformType> EveryNth<TransformType> {
+    /// Creates a transform applying `transform` on every `n`th call, passing the input of the
+    /// other calls through.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn new(transform: TransformType, n: usize) -> Self {
+        Self::with_skipped(transform, n, PassThrough)
+    }
+}
+
+impl<TransformType> EveryNth<TransformType, Discard> {
+    /// Creates a transform applying `transform` on every `n`th call, yielding `None` for the other
+    /// calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn discarding(transform: TransformType, n: usize) -> Self {
+        Self::with_skipped(transform, n, Discard)
+    }
+}
+
+impl<TransformType, Skipped> EveryNth<TransformType, Skipped> {
+    fn with_skipped(transform: TransformType, n: usize, skipped: Skipped) -> Self {
+        assert!(n > 0, "every nth call requires a non-zero n");
+        Self {
+            transform,
+            n,
+            calls: 0,
+            skipped,
+        }
+    }
+
+    /// The number of calls between two applications of the transform.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Counts a call, returning whether it is one the transform applies to.
+    fn advance(&mut self) -> bool {
+        self.calls = (self.calls + 1) % self.n;
+        self.calls == 0
+    }
+}
+
+impl<Input, TransformType> OnceTransform<Input> for EveryNth<TransformType>
+where
+    TransformType: OnceTransform<Input, Output = Input>,
+    Input: SendSync,
+{
+    type Output = Input;
+
+    fn apply_once(mut self, input: Input) -> Self::Output {
+        if self.advance() {
+            self.transform.apply_once(input)
+        } else {
+            input
+        }
+    }
+}
+
+impl<Input, TransformType> MutableTransform<Input> for EveryNth<TransformType>
+where
+    TransformType: MutableTransform<Input, Output = Input>,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        if self.advance() {
+            self.transform.apply_mut(input)
+        } else {
+            input
+        }
+    }
+}
+
+impl<Input, TransformType> OnceTransform<Input> for EveryNth<TransformType, Discard>
+where
+    TransformType: OnceTransform<Input>,
+    Input: SendSync,
+{
+    type Output = Option<TransformType::Output>;
+
+    fn apply_once(mut self, input: Input) -> Self::Output {
+        self.advance().then(|| self.transform.apply_once(input))
+    }
+}
+
+impl<Input, TransformType> MutableTransform<Input> for EveryNth<TransformType, Discard>
+where
+    TransformType: MutableTransform<Input>,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.advance().then(|| self.transform.apply_mut(input))
+    }
+}
+
+/// A transform folding its input through a sequence of stages sharing the same input and output
+/// type.
+///
+/// Stages are applied in the order they were pushed and an empty pipeline yields its input
+/// unchanged, like [`Identity`].
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let empty: Pipeline<Add<i32>, i32> = Pipeline::new();
+///     assert!(empty.is_empty());
+///     assert_eq!(empty.apply(7), 7);
+///
+///     let n = 4;
+///     let mut pipeline: Pipeline<_, i32> = (1..=n).map(Add).collect::<Vec<_>>().into();
+///     pipeline.push(Add(n + 1));
+///     assert_eq!(pipeline.len(), 5);
+///
+///     let total = pipeline.apply(0);
+///     println!("{total}");
+/// # assert_eq!(total, 15);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pipeline<TransformType, Input> {
+    stages: Vec<TransformType>,
+    input: PhantomData<fn(Input) -> Input>,
+}
+
+impl<TransformType, Input> Pipeline<TransformType, Input> {
+    /// Creates an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a stage to the end of the pipeline.
+    pub fn push(&mut self, stage: TransformType) {
+        self.stages.push(stage);
+    }
+
+    /// The number of stages.
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Whether the pipeline has no stage.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+impl<TransformType, Input> Default for Pipeline<TransformType, Input> {
+    fn default() -> Self {
+        Self {
+            stages: Vec::new(),
+            input: PhantomData,
+        }
+    }
+}
+
+impl<TransformType, Input> From<Vec<TransformType>> for Pipeline<TransformType, Input> {
+    fn from(stages: Vec<TransformType>) -> Self {
+        Self {
+            stages,
+            input: PhantomData,
+        }
+    }
+}
+
+impl<TransformType, Input> OnceTransform<Input> for Pipeline<TransformType, Input>
+where
+    TransformType: OnceTransform<Input, Output = Input>,
+    Input: SendSync,
+{
+    type Output = Input;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.stages
+            .into_iter()
+            .fold(input, |input, stage| stage.apply_once(input))
+    }
+}
+
+impl<TransformType, Input> MutableTransform<Input> for Pipeline<TransformType, Input>
+where
+    TransformType: MutableTransform<Input, Output = Input>,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.stages
+            .iter_mut()
+            .fold(input, |input, stage| stage.apply_mut(input))
+    }
+}
+
+impl<TransformType, Input> Transform<Input> for Pipeline<TransformType, Input>
+where
+    TransformType: Transform<Input, Output = Input>,
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.stages
+            .iter()
+            .fold(input, |input, stage| stage.apply(input))
+    }
+}
+
+/// A transform checking that the outputs of another transform satisfy an invariant.
+///
+/// The invariant is only checked in debug builds, where an output violating it panics with the
+/// output in the message. Release builds pass the outputs through unchecked.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let abs = AssertInvariant::new(from_fn(|x: i32| x.abs()), |output: &i32| *output >= 0);
+///     assert_eq!(abs.apply(-3), 3);
+/// }
+/// ```
+/// Violating the invariant panics when this crate is built with debug assertions, and passes the
+/// output through unchecked otherwise:
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::panic;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let negate = AssertInvariant::new(from_fn(|x: i32| -x), |output: &i32| *output >= 0);
+///     if cfg!(debug_assertions) {
+///         let result = panic::catch_unwind(|| negate.apply(3));
+///         assert!(result.is_err());
+///         let error = result.unwrap_err();
+///         assert_eq!(
+///             error.downcast_ref::<String>().map(String::as_str),
+///             Some("transform output -3 violates its invariant")
+///         );
+///     } else {
+///         assert_eq!(negate.apply(3), -3);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AssertInvariant<TransformType, Invariant> {
+    transform: TransformType,
+    invariant: Invariant,
+}
+
+impl<TransformType, Invariant> AssertInvariant<TransformType, Invariant> {
+    /// Creates a transform checking that the outputs of `transform` satisfy `invariant`.
+    pub fn new(transform: TransformType, invariant: Invariant) -> Self {
+        Self {
+            transform,
+            invariant,
+        }
+    }
+}
+
+/// Checks in debug builds that `output` satisfies `invariant`, then returns it.
+fn checked<Output, Invariant>(invariant: &Invariant, output: Output) -> Output
+where
+    Output: Debug,
+    Invariant: Fn(&Output) -> bool,
+{
+    debug_assert!(
+        invariant(&output),
+        "transform output {:?} violates its invariant",
+        output
+    );
+    output
+}
+
+impl<Input, TransformType, Invariant> OnceTransform<Input>
+    for AssertInvariant<TransformType, Invariant>
+where
+    TransformType: OnceTransform<Input>,
+    TransformType::Output: Debug,
+    Invariant: Fn(&TransformType::Output) -> bool + SendSync,
+    Input: SendSync,
+{
+    type Output = TransformType::Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        checked(&self.invariant, self.transform.apply_once(input))
+    }
+}
+
+impl<Input, TransformType, Invariant> MutableTransform<Input>
+    for AssertInvariant<TransformType, Invariant>
+where
+    TransformType: MutableTransform<Input>,
+    TransformType::Output: Debug,
+    Invariant: Fn(&TransformType::Output) -> bool + SendSync,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        checked(&self.invariant, self.transform.apply_mut(input))
+    }
+}
+
+impl<Input, TransformType, Invariant> Transform<Input> for AssertInvariant<TransformType, Invariant>
+where
+    TransformType: Transform<Input>,
+    TransformType::Output: Debug,
+    Invariant: Fn(&TransformType::Output) -> bool + SendSync,
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        checked(&self.invariant, self.transform.apply(input))
+    }
+}
@@ -0,0 +1,104 @@
+//! Transform pipelines described by data.
+//!
+//! A [`PipelineSpec`] lists the stages of a numeric pipeline and [`build`] turns it into a single
+//! transform, so pipelines can be assembled from a description known only at runtime, such as a
+//! configuration file. Descriptions are (de)serialized with `serde`, which requires the `serde`
+//! feature.
+//! ```
+//! # use viewbuilder_core as viewbuilder;
+//! use viewbuilder::transform::{
+//!     config::*,
+//!     Transform,
+//! };
+//!
+//! fn main() {
+//!     let json = concat!(
+//!         r#"{"stages":[{"Add":1.0},{"Mul":10.0},"#,
+//!         r#"{"Clamp":{"min":0.0,"max":25.0}},"Saturate"]}"#,
+//!     );
+//!     let spec: PipelineSpec = serde_json::from_str(json).unwrap();
+//!     assert_eq!(
+//!         spec,
+//!         PipelineSpec::new(vec![
+//!             Stage::Add(1.0),
+//!             Stage::Mul(10.0),
+//!             Stage::Clamp {
+//!                 min: 0.0,
+//!                 max: 25.0,
+//!             },
+//!             Stage::Saturate,
+//!         ])
+//!     );
+//!     assert_eq!(serde_json::to_string(&spec).unwrap(), json);
+//!
+//!     let pipeline = build(&spec);
+//!     assert_eq!(pipeline.apply(-2.0), 0.0);
+//!     assert_eq!(pipeline.apply(2.0), 1.0);
+//!     assert_eq!(build(&PipelineSpec::default()).apply(2.0), 2.0);
+//! }
+//! ```
+
+use super::*;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// A stage of a numeric pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Stage {
+    /// Adds a value to the input.
+    Add(f64),
+    /// Multiplies the input by a value.
+    Mul(f64),
+    /// Clamps the input to the `[min, max]` range.
+    Clamp {
+        /// The lower bound of the range.
+        min: f64,
+        /// The upper bound of the range.
+        max: f64,
+    },
+    /// Clamps the input to the `[0.0, 1.0]` range, see [`Saturate`].
+    Saturate,
+}
+
+/// The ordered list of stages of a numeric pipeline.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PipelineSpec {
+    stages: Vec<Stage>,
+}
+
+impl PipelineSpec {
+    /// Creates a pipeline description from its `stages`, in the order they apply.
+    pub fn new(stages: Vec<Stage>) -> Self {
+        Self { stages }
+    }
+
+    /// The stages, in the order they apply.
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+}
+
+/// The transform of a single `stage`.
+fn build_stage(stage: Stage) -> BoxedTransform<f64, f64> {
+    match stage {
+        Stage::Add(value) => Add(value).boxed(),
+        Stage::Mul(value) => Mul(value).boxed(),
+        Stage::Clamp { min, max } => from_fn(move |input: f64| input.clamp(min, max)).boxed(),
+        Stage::Saturate => Saturate.boxed(),
+    }
+}
+
+/// Builds the transform applying the stages of a `spec` in order.
+///
+/// An empty description yields its input unchanged.
+///
+/// # Panics
+///
+/// The built transform panics when applied if a [`Stage::Clamp`] has a `min` greater than its
+/// `max`, as [`f64::clamp`] does.
+pub fn build(spec: &PipelineSpec) -> BoxedTransform<f64, f64> {
+    let stages: Vec<_> = spec.stages().iter().copied().map(build_stage).collect();
+    Pipeline::from(stages).boxed()
+}
@@ -0,0 +1,56 @@
+//! [`Constant`](crate::transform::Constant) definition.
+
+use super::*;
+
+/// A transform ignoring its input and returning a fixed value.
+///
+/// The borrowing [`apply`](Transform::apply) and [`apply_mut`](MutableTransform::apply_mut)
+/// return a clone of the value, while the consuming [`apply_once`](OnceTransform::apply_once)
+/// moves it out without cloning.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let stub = Constant(String::from("stub"));
+///     assert_eq!(stub.apply(1), "stub");
+///     assert_eq!(stub.apply("ignored"), "stub");
+///     assert_eq!(stub.apply_once(()), "stub");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Constant<Value>(pub Value);
+
+impl<Input, Value> OnceTransform<Input> for Constant<Value>
+where
+    Input: SendSync,
+    Value: Clone + SendSync,
+{
+    type Output = Value;
+
+    fn apply_once(self, _input: Input) -> Self::Output {
+        let Self(value) = self;
+        value
+    }
+}
+
+impl<Input, Value> MutableTransform<Input> for Constant<Value>
+where
+    Input: SendSync,
+    Value: Clone + SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Value> Transform<Input> for Constant<Value>
+where
+    Input: SendSync,
+    Value: Clone + SendSync,
+{
+    fn apply(&self, _input: Input) -> Self::Output {
+        let Self(value) = self;
+        value.clone()
+    }
+}
@@ -0,0 +1,110 @@
+//! Transform operations reading an ambient context.
+
+use super::*;
+
+/// A transform operation reading an ambient context alongside its input.
+///
+/// The context holds values, like a configuration or a theme, that shape the operation without
+/// being part of its input. Binding a context with [`ContextualTransform::with_context`] yields a
+/// plain [`Transform`].
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug)]
+/// struct Theme {
+///     scale: f64,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Scale;
+///
+/// impl ContextualTransform<Theme, f64> for Scale {
+///     type Output = f64;
+///
+///     fn apply_with(&self, context: &Theme, input: f64) -> Self::Output {
+///         input * context.scale
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Scale.apply_with(&Theme { scale: 3.0 }, 2.0), 6.0);
+///
+///     let scale = Scale.with_context(Theme { scale: 2.0 });
+///     assert_eq!(scale.apply(4.0), 8.0);
+///     assert_eq!(scale.context().scale, 2.0);
+/// }
+/// ```
+pub trait ContextualTransform<Context, Input>
+where
+    Self: SendSync,
+    Input: SendSync,
+{
+    /// The output of the operation.
+    type Output: SendSync;
+
+    /// Applies the transform operation to the `input` within the `context`.
+    fn apply_with(&self, context: &Context, input: Input) -> Self::Output;
+
+    /// Binds a `context` to the transform, yielding a transform of the input alone.
+    fn with_context(self, context: Context) -> WithContext<Self, Context>
+    where
+        Self: Sized,
+    {
+        WithContext {
+            transform: self,
+            context,
+        }
+    }
+}
+
+/// A [`ContextualTransform`] bound to its context.
+///
+/// Created by [`ContextualTransform::with_context`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WithContext<TransformType, Context> {
+    transform: TransformType,
+    context: Context,
+}
+
+impl<TransformType, Context> WithContext<TransformType, Context> {
+    /// The bound context.
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+}
+
+impl<Input, TransformType, Context> OnceTransform<Input> for WithContext<TransformType, Context>
+where
+    TransformType: ContextualTransform<Context, Input>,
+    Context: SendSync,
+    Input: SendSync,
+{
+    type Output = TransformType::Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, TransformType, Context> MutableTransform<Input> for WithContext<TransformType, Context>
+where
+    TransformType: ContextualTransform<Context, Input>,
+    Context: SendSync,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, TransformType, Context> Transform<Input> for WithContext<TransformType, Context>
+where
+    TransformType: ContextualTransform<Context, Input>,
+    Context: SendSync,
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.transform.apply_with(&self.context, input)
+    }
+}
@@ -0,0 +1,114 @@
+//! Clone-on-write transform operations.
+
+use super::*;
+use std::{
+    borrow::{
+        Borrow,
+        Cow,
+    },
+    marker::PhantomData,
+};
+
+/// A transform operation borrowing its input and only allocating when it modifies it.
+///
+/// Transforms that usually leave their input unchanged can return [`Cow::Borrowed`] and avoid
+/// cloning it.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::borrow::Cow;
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug)]
+/// struct Uppercase;
+///
+/// impl CowTransform<str> for Uppercase {
+///     fn apply_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+///         if input.chars().any(char::is_lowercase) {
+///             Cow::Owned(input.to_uppercase())
+///         } else {
+///             Cow::Borrowed(input)
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     assert!(matches!(Uppercase.apply_cow("HELLO"), Cow::Borrowed("HELLO")));
+///     assert!(matches!(Uppercase.apply_cow("hello"), Cow::Owned(value) if value == "HELLO"));
+///
+///     let owned = Owned::new(Uppercase);
+///     assert_eq!(owned.apply(String::from("hello")), "HELLO");
+/// }
+/// ```
+pub trait CowTransform<Input>
+where
+    Self: SendSync,
+    Input: ToOwned + ?Sized,
+{
+    /// Apply the transform operation borrowing the input, yielding either the input itself or a
+    /// modified owned copy.
+    fn apply_cow<'a>(&self, input: &'a Input) -> Cow<'a, Input>;
+}
+
+/// Adapts a [`CowTransform`] into a [`Transform`] over owned values.
+///
+/// When the inner transform borrows, the owned input is returned as is rather than cloned.
+#[derive(Debug, Clone, Copy)]
+pub struct Owned<TransformType, Input>
+where
+    Input: ?Sized,
+{
+    transform: TransformType,
+    input: PhantomData<fn(&Input)>,
+}
+
+impl<TransformType, Input> Owned<TransformType, Input>
+where
+    TransformType: CowTransform<Input>,
+    Input: ToOwned + ?Sized,
+{
+    /// Wraps a clone-on-write `transform`.
+    pub fn new(transform: TransformType) -> Self {
+        Self {
+            transform,
+            input: PhantomData,
+        }
+    }
+}
+
+impl<TransformType, Input> OnceTransform<Input::Owned> for Owned<TransformType, Input>
+where
+    TransformType: CowTransform<Input>,
+    Input: ToOwned + ?Sized,
+    Input::Owned: SendSync,
+{
+    type Output = Input::Owned;
+
+    fn apply_once(self, input: Input::Owned) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<TransformType, Input> MutableTransform<Input::Owned> for Owned<TransformType, Input>
+where
+    TransformType: CowTransform<Input>,
+    Input: ToOwned + ?Sized,
+    Input::Owned: SendSync,
+{
+    fn apply_mut(&mut self, input: Input::Owned) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<TransformType, Input> Transform<Input::Owned> for Owned<TransformType, Input>
+where
+    TransformType: CowTransform<Input>,
+    Input: ToOwned + ?Sized,
+    Input::Owned: SendSync,
+{
+    fn apply(&self, input: Input::Owned) -> Self::Output {
+        match self.transform.apply_cow(input.borrow()) {
+            Cow::Borrowed(_) => input,
+            Cow::Owned(output) => output,
+        }
+    }
+}
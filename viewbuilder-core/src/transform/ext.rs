@@ -0,0 +1,133 @@
+//! [`TransformExt`](crate::transform::TransformExt) definition.
+
+use super::*;
+
+/// Combinators available on every transform.
+///
+/// Each combinator consumes the transform and wraps it into a new transform.
+pub trait TransformExt
+where
+    Self: SendSync + Sized,
+{
+    /// Chains the `next` transform after this one, feeding it this transform's output.
+    ///
+    /// Chaining is associative: `a.then(b).then(c)` and `a.then(b.then(c))` produce the same
+    /// outputs.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::transform::*;
+    ///
+    /// fn main() {
+    ///     let (add1, add2) = (Add(1), Add(2));
+    ///     let left = add1.then(add2).then(Mul(2));
+    ///     let right = add1.then(add2.then(Mul(2)));
+    ///     assert_eq!(left.apply(0), 6);
+    ///     for input in -10..10 {
+    ///         assert_eq!(left.apply(input), right.apply(input));
+    ///     }
+    /// }
+    /// ```
+    fn then<Next>(self, next: Next) -> Then<Self, Next> {
+        Then::new(self, next)
+    }
+
+    /// Adapts the input of the transform, feeding it the output of `map`.
+    ///
+    /// Pairs with [`map_output`](TransformExt::map_output).
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::transform::*;
+    ///
+    /// fn main() {
+    ///     let double = Mul(2);
+    ///     let parse = from_fn(|text: &str| text.parse::<i32>().unwrap());
+    ///     let parse_and_double = double.map_input(parse);
+    ///     assert_eq!(parse_and_double.apply("21"), 42);
+    /// }
+    /// ```
+    fn map_input<Map>(self, map: Map) -> Then<Map, Self> {
+        Then::new(map, self)
+    }
+
+    /// Post-processes the output of the transform with `map`.
+    ///
+    /// Pairs with [`map_input`](TransformExt::map_input).
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::transform::*;
+    ///
+    /// fn main() {
+    ///     let increment = Add(1);
+    ///     let describe = increment.map_output(from_fn(|x: i32| x.to_string()));
+    ///     assert_eq!(describe.apply(41), "42");
+    /// }
+    /// ```
+    fn map_output<Map>(self, map: Map) -> Then<Self, Map> {
+        Then::new(self, map)
+    }
+
+    /// Adapts both ends of the transform at once, feeding it the output of `map_input` and
+    /// post-processing its output with `map_output`.
+    ///
+    /// Equivalent to chaining [`map_input`](TransformExt::map_input) and
+    /// [`map_output`](TransformExt::map_output).
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::transform::*;
+    ///
+    /// fn main() {
+    ///     let square = from_fn(|x: i32| x * x);
+    ///     let describe = square.bimap(
+    ///         from_fn(|text: &str| text.trim().parse::<i32>().unwrap()),
+    ///         from_fn(|x: i32| format!("{x} squared")),
+    ///     );
+    ///     let output: String = describe.apply(" 7 ");
+    ///     println!("{output}");
+    /// # assert_eq!(output, "49 squared");
+    /// }
+    /// ```
+    fn bimap<MapInput, MapOutput>(
+        self,
+        map_input: MapInput,
+        map_output: MapOutput,
+    ) -> Then<Then<MapInput, Self>, MapOutput> {
+        Then::new(Then::new(map_input, self), map_output)
+    }
+
+    /// Erases the type of the transform into a [`BoxedTransform`].
+    ///
+    /// See [`BoxedTransform`] for the cost of the dynamic dispatch.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::transform::*;
+    ///
+    /// fn main() {
+    ///     let increment: BoxedTransform<i32, i32> = Add(1).boxed();
+    ///     assert_eq!(increment.apply(41), 42);
+    /// }
+    /// ```
+    fn boxed<Input, Output>(self) -> BoxedTransform<Input, Output>
+    where
+        Self: Transform<Input, Output = Output> + 'static,
+        Input: SendSync,
+        Output: SendSync,
+    {
+        BoxedTransform::new(self)
+    }
+
+    /// Wraps the transform into an [`IntoTry`], a fallible transform that never fails.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::transform::*;
+    ///
+    /// fn main() {
+    ///     let increment = Add(1).into_try();
+    ///     assert_eq!(increment.try_apply(41), Ok(42));
+    /// }
+    /// ```
+    fn into_try(self) -> IntoTry<Self> {
+        IntoTry::new(self)
+    }
+}
+
+impl<TransformType> TransformExt for TransformType where TransformType: SendSync {}
@@ -0,0 +1,334 @@
+//! Fallible transform operations.
+
+use super::*;
+use std::convert::Infallible;
+
+/// A transform operation that can fail.
+///
+/// A [`Transform`] becomes a fallible transform that never fails through
+/// [`into_try`](TransformExt::into_try).
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug)]
+/// struct Parse;
+///
+/// impl<'a> TryTransform<&'a str> for Parse {
+///     type Output = i32;
+///     type Error = String;
+///
+///     fn try_apply(&self, input: &'a str) -> Result<Self::Output, Self::Error> {
+///         input
+///             .parse()
+///             .map_err(|_| format!("{input:?} is not a number"))
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Parse.try_apply("42"), Ok(42));
+///     assert_eq!(
+///         Parse.try_apply("forty-two"),
+///         Err("\"forty-two\" is not a number".to_string())
+///     );
+///
+///     let increment = Add(1).into_try();
+///     assert_eq!(increment.try_apply(1), Ok(2));
+/// }
+/// ```
+pub trait TryTransform<Input>
+where
+    Self: SendSync,
+    Input: SendSync,
+{
+    /// The output of the operation when it succeeds.
+    type Output: SendSync;
+
+    /// The error of the operation when it fails.
+    type Error: SendSync;
+
+    /// Apply the transform operation to the input, yielding the output or the reason it failed.
+    fn try_apply(&self, input: Input) -> Result<Self::Output, Self::Error>;
+
+    /// Falls back to the `fallback` transform, applied to a copy of the input, when this one
+    /// fails.
+    ///
+    /// See [`OrElse`].
+    fn or_else<Fallback>(self, fallback: Fallback) -> OrElse<Self, Fallback>
+    where
+        Self: Sized,
+        Fallback: Transform<Input, Output = Self::Output>,
+        Input: Clone,
+    {
+        OrElse::new(self, fallback)
+    }
+}
+
+/// A fallible transform wrapping a transform that cannot fail, with an [`Infallible`] error.
+///
+/// Created with [`into_try`](TransformExt::into_try), it lets infallible transforms take part
+/// in fallible ones, such as [`Require`].
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let double = Mul(2).into_try();
+///     assert_eq!(double.try_apply(21), Ok(42));
+///
+///     let share = Require::new(
+///         |parts: &i32| *parts != 0,
+///         from_fn(|parts: i32| 100 / parts).into_try(),
+///     );
+///     assert_eq!(share.try_apply(4), Ok(25));
+///     assert_eq!(share.try_apply(0), Err(RequireError::Rejected(0)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IntoTry<TransformType>(TransformType);
+
+impl<TransformType> IntoTry<TransformType> {
+    /// Creates a fallible transform always succeeding with the output of `transform`.
+    pub fn new(transform: TransformType) -> Self {
+        Self(transform)
+    }
+
+    /// Returns the wrapped transform.
+    pub fn into_inner(self) -> TransformType {
+        let Self(transform) = self;
+        transform
+    }
+}
+
+impl<Input, TransformType> TryTransform<Input> for IntoTry<TransformType>
+where
+    TransformType: Transform<Input>,
+    Input: SendSync,
+{
+    type Output = TransformType::Output;
+    type Error = Infallible;
+
+    fn try_apply(&self, input: Input) -> Result<Self::Output, Self::Error> {
+        let Self(transform) = self;
+        Ok(transform.apply(input))
+    }
+}
+
+/// A fallible transform applied to the output of another, short-circuiting on the first error.
+///
+/// Both transforms fail with the same error type.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug)]
+/// struct Parse;
+///
+/// impl<'a> TryTransform<&'a str> for Parse {
+///     type Output = i32;
+///     type Error = String;
+///
+///     fn try_apply(&self, input: &'a str) -> Result<Self::Output, Self::Error> {
+///         input
+///             .parse()
+///             .map_err(|_| format!("{input:?} is not a number"))
+///     }
+/// }
+///
+/// #[derive(Debug)]
+/// struct Reciprocal;
+///
+/// impl TryTransform<i32> for Reciprocal {
+///     type Output = f64;
+///     type Error = String;
+///
+///     fn try_apply(&self, input: i32) -> Result<Self::Output, Self::Error> {
+///         if input == 0 {
+///             Err("zero has no reciprocal".to_string())
+///         } else {
+///             Ok(1.0 / input as f64)
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let reciprocal = TryThen::new(Parse, Reciprocal);
+///     assert_eq!(reciprocal.try_apply("4"), Ok(0.25));
+///     assert_eq!(
+///         reciprocal.try_apply("0"),
+///         Err("zero has no reciprocal".to_string())
+///     );
+///     assert_eq!(
+///         reciprocal.try_apply("four"),
+///         Err("\"four\" is not a number".to_string())
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TryThen<First, Next> {
+    first: First,
+    next: Next,
+}
+
+impl<First, Next> TryThen<First, Next> {
+    /// Creates a transform applying `next` to the output of `first` unless it failed.
+    pub fn new(first: First, next: Next) -> Self {
+        Self { first, next }
+    }
+}
+
+impl<Input, First, Next> TryTransform<Input> for TryThen<First, Next>
+where
+    First: TryTransform<Input>,
+    Next: TryTransform<First::Output, Error = First::Error>,
+    Input: SendSync,
+{
+    type Output = Next::Output;
+    type Error = First::Error;
+
+    fn try_apply(&self, input: Input) -> Result<Self::Output, Self::Error> {
+        self.next.try_apply(self.first.try_apply(input)?)
+    }
+}
+
+/// The error of a [`Require`] transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RequireError<Input, Error> {
+    /// The input failed the precondition and is handed back.
+    Rejected(Input),
+    /// The input passed the precondition but the inner transform failed.
+    Failed(Error),
+}
+
+/// A fallible transform applying another transform only to the inputs satisfying a precondition.
+///
+/// Inputs failing the precondition are rejected before reaching the inner transform.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let sqrt = Require::new(
+///         |input: &f64| *input >= 0.0,
+///         from_fn(|input: f64| input.sqrt()).into_try(),
+///     );
+///     assert_eq!(sqrt.try_apply(4.0), Ok(2.0));
+///     assert_eq!(sqrt.try_apply(-4.0), Err(RequireError::Rejected(-4.0)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Require<Predicate, TransformType> {
+    predicate: Predicate,
+    transform: TransformType,
+}
+
+impl<Predicate, TransformType> Require<Predicate, TransformType> {
+    /// Creates a transform applying `transform` to the inputs for which `predicate` returns
+    /// `true`.
+    pub fn new(predicate: Predicate, transform: TransformType) -> Self {
+        Self {
+            predicate,
+            transform,
+        }
+    }
+}
+
+impl<Input, Predicate, TransformType> TryTransform<Input> for Require<Predicate, TransformType>
+where
+    Predicate: Fn(&Input) -> bool + SendSync,
+    TransformType: TryTransform<Input>,
+    Input: SendSync,
+{
+    type Output = TransformType::Output;
+    type Error = RequireError<Input, TransformType::Error>;
+
+    fn try_apply(&self, input: Input) -> Result<Self::Output, Self::Error> {
+        if !(self.predicate)(&input) {
+            return Err(RequireError::Rejected(input));
+        }
+
+        self.transform
+            .try_apply(input)
+            .map_err(RequireError::Failed)
+    }
+}
+
+/// A transform falling back to another transform when a fallible one fails.
+///
+/// The primary transform is given a copy of the input, so the fallback can still be applied to it.
+/// As the fallback cannot fail, neither can the combined transform.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug)]
+/// struct StrictParse;
+///
+/// impl<'a> TryTransform<&'a str> for StrictParse {
+///     type Output = i32;
+///     type Error = String;
+///
+///     fn try_apply(&self, input: &'a str) -> Result<Self::Output, Self::Error> {
+///         input
+///             .parse()
+///             .map_err(|_| format!("{input:?} is not a number"))
+///     }
+/// }
+///
+/// fn main() {
+///     let lenient = from_fn(|input: &str| input.trim().parse().unwrap_or(0));
+///     let parse = StrictParse.or_else(lenient);
+///     assert_eq!(parse.apply("42"), 42);
+///     assert_eq!(parse.apply(" 42 "), 42);
+///     assert_eq!(parse.apply("forty-two"), 0);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OrElse<Primary, Fallback> {
+    primary: Primary,
+    fallback: Fallback,
+}
+
+impl<Primary, Fallback> OrElse<Primary, Fallback> {
+    /// Creates a transform applying `fallback` to the inputs for which `primary` fails.
+    pub fn new(primary: Primary, fallback: Fallback) -> Self {
+        Self { primary, fallback }
+    }
+}
+
+impl<Input, Primary, Fallback> OnceTransform<Input> for OrElse<Primary, Fallback>
+where
+    Primary: TryTransform<Input>,
+    Fallback: Transform<Input, Output = Primary::Output>,
+    Input: Clone + SendSync,
+{
+    type Output = Primary::Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Primary, Fallback> MutableTransform<Input> for OrElse<Primary, Fallback>
+where
+    Primary: TryTransform<Input>,
+    Fallback: Transform<Input, Output = Primary::Output>,
+    Input: Clone + SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Input, Primary, Fallback> Transform<Input> for OrElse<Primary, Fallback>
+where
+    Primary: TryTransform<Input>,
+    Fallback: Transform<Input, Output = Primary::Output>,
+    Input: Clone + SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.primary
+            .try_apply(input.clone())
+            .unwrap_or_else(|_| self.fallback.apply(input))
+    }
+}
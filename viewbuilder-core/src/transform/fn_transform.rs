@@ -0,0 +1,83 @@
+//! [`FnTransform`](crate::transform::FnTransform) definition.
+
+use super::*;
+
+/// A transform calling a closure.
+///
+/// [`Fn`] closures make a [`Transform`], [`FnMut`] closures a [`MutableTransform`] and [`FnOnce`]
+/// closures a [`OnceTransform`]. Closures are wrapped rather than being transforms themselves, as
+/// such an implementation would overlap with the ones of references to transforms.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn twice<T>(transform: T, input: i32) -> i32
+/// where
+///     T: Transform<i32, Output = i32>,
+/// {
+///     transform.apply(transform.apply(input))
+/// }
+///
+/// fn main() {
+///     let output = twice(from_fn(|x: i32| x + 1), 0);
+///     println!("{output}");
+/// # assert_eq!(output, 2);
+///
+///     let mut total = 0;
+///     let mut accumulate = from_fn(|x: i32| {
+///         total += x;
+///         total
+///     });
+///     accumulate.apply_mut(2);
+///     assert_eq!(accumulate.apply_mut(3), 5);
+///
+///     let label = String::from("count");
+///     let describe = from_fn(move |x: i32| format!("{label}: {x}"));
+///     assert_eq!(describe.apply_once(3), "count: 3");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FnTransform<Closure>(pub Closure);
+
+/// Wraps a `closure` into a transform, see [`FnTransform`].
+pub fn from_fn<Closure>(closure: Closure) -> FnTransform<Closure> {
+    FnTransform(closure)
+}
+
+impl<Input, Output, Closure> OnceTransform<Input> for FnTransform<Closure>
+where
+    Closure: FnOnce(Input) -> Output + SendSync,
+    Input: SendSync,
+    Output: SendSync,
+{
+    type Output = Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        let Self(closure) = self;
+        closure(input)
+    }
+}
+
+impl<Input, Output, Closure> MutableTransform<Input> for FnTransform<Closure>
+where
+    Closure: FnMut(Input) -> Output + SendSync,
+    Input: SendSync,
+    Output: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        let Self(closure) = self;
+        closure(input)
+    }
+}
+
+impl<Input, Output, Closure> Transform<Input> for FnTransform<Closure>
+where
+    Closure: Fn(Input) -> Output + SendSync,
+    Input: SendSync,
+    Output: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        let Self(closure) = self;
+        closure(input)
+    }
+}
@@ -0,0 +1,50 @@
+//! [`Identity`](crate::transform::Identity) definition.
+
+use super::*;
+
+/// A transform returning its input unchanged.
+///
+/// Useful as a no-op default in generic code and as the base case when folding a sequence of
+/// transforms.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     assert_eq!(Identity.apply(42), 42);
+///     assert_eq!(Identity.apply("text"), "text");
+///     assert_eq!(Identity.apply(vec![1.0, 2.0]), vec![1.0, 2.0]);
+///     assert_eq!(Identity.then(from_fn(|x: i32| x + 1)).apply(1), 2);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Identity;
+
+impl<Input> OnceTransform<Input> for Identity
+where
+    Input: SendSync,
+{
+    type Output = Input;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        input
+    }
+}
+
+impl<Input> MutableTransform<Input> for Identity
+where
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        input
+    }
+}
+
+impl<Input> Transform<Input> for Identity
+where
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        input
+    }
+}
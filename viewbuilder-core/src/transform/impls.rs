@@ -1,4 +1,79 @@
 //! [`transform`](crate::transform) module default implementations.
+//!
+//! References to transforms are transforms too: shared references to a [`Transform`] implement
+//! every level, and mutable references to a [`MutableTransform`] the mutable and once levels.
+//! ```
+//! # use viewbuilder_core as viewbuilder;
+//! use viewbuilder::transform::*;
+//!
+//! fn twice<T>(transform: T, input: f64) -> f64
+//! where
+//!     T: Transform<f64, Output = f64>,
+//! {
+//!     transform.apply(transform.apply(input))
+//! }
+//!
+//! fn feed<T>(mut transform: T, inputs: Vec<f64>) -> Option<f64>
+//! where
+//!     T: MutableTransform<f64, Output = f64>,
+//! {
+//!     inputs
+//!         .into_iter()
+//!         .map(|input| transform.apply_mut(input))
+//!         .last()
+//! }
+//!
+//! fn main() {
+//!     let saturate = Saturate;
+//!     assert_eq!(twice(&saturate, 2.0), 1.0);
+//!
+//!     let mut total = 0.0;
+//!     let mut accumulate = from_fn(move |input: f64| {
+//!         total += input;
+//!         total
+//!     });
+//!     assert_eq!(feed(&mut accumulate, vec![1.0, 2.0]), Some(3.0));
+//!     assert_eq!(accumulate.apply_mut(4.0), 7.0);
+//! }
+//! ```
+//!
+//! Tuples of transforms are transforms too, each element being applied to the output of the
+//! previous one, from the first to the last, for tuples of two to eight elements.
+//! ```
+//! # use viewbuilder_core as viewbuilder;
+//! use viewbuilder::transform::*;
+//!
+//! fn main() {
+//!     assert_eq!((Add(1), Add(2)).apply(0), 3);
+//!     assert_eq!((Add(1), from_fn(|x: i32| x * 10), Add(2)).apply(0), 12);
+//!     let describe = (
+//!         Add(1),
+//!         Add(2),
+//!         from_fn(|x: i32| x * 2),
+//!         from_fn(|x: i32| x.to_string()),
+//!     );
+//!     assert_eq!(describe.apply(1), "8");
+//!
+//!     let mut calls = 0;
+//!     let mut pipeline = (
+//!         Add(1),
+//!         from_fn(|x: i32| {
+//!             calls += 1;
+//!             x * calls
+//!         }),
+//!     );
+//!     assert_eq!(pipeline.apply_mut(1), 2);
+//!     assert_eq!(pipeline.apply_mut(1), 4);
+//!
+//!     let label = String::from("total");
+//!     let pipeline = (
+//!         Add(1),
+//!         Add(2),
+//!         from_fn(move |x: i32| format!("{label}: {x}")),
+//!     );
+//!     assert_eq!(pipeline.apply_once(0), "total: 3");
+//! }
+//! ```
 
 use super::*;
 
@@ -55,3 +130,62 @@ where
         (*self).apply_mut(input)
     }
 }
+
+macro_rules! impl_transform_for_tuple {
+    ($first:ident $(, $name:ident => $previous:ident)+; $last:ident) => {
+        impl<Input, $first, $($name),+> OnceTransform<Input> for ($first, $($name),+)
+        where
+            $first: OnceTransform<Input>,
+            $($name: OnceTransform<$previous::Output>,)+
+            Input: SendSync,
+        {
+            type Output = $last::Output;
+
+            #[allow(non_snake_case)]
+            fn apply_once(self, input: Input) -> Self::Output {
+                let ($first, $($name),+) = self;
+                let output = $first.apply_once(input);
+                $(let output = $name.apply_once(output);)+
+                output
+            }
+        }
+
+        impl<Input, $first, $($name),+> MutableTransform<Input> for ($first, $($name),+)
+        where
+            $first: MutableTransform<Input>,
+            $($name: MutableTransform<$previous::Output>,)+
+            Input: SendSync,
+        {
+            #[allow(non_snake_case)]
+            fn apply_mut(&mut self, input: Input) -> Self::Output {
+                let ($first, $($name),+) = self;
+                let output = $first.apply_mut(input);
+                $(let output = $name.apply_mut(output);)+
+                output
+            }
+        }
+
+        impl<Input, $first, $($name),+> Transform<Input> for ($first, $($name),+)
+        where
+            $first: Transform<Input>,
+            $($name: Transform<$previous::Output>,)+
+            Input: SendSync,
+        {
+            #[allow(non_snake_case)]
+            fn apply(&self, input: Input) -> Self::Output {
+                let ($first, $($name),+) = self;
+                let output = $first.apply(input);
+                $(let output = $name.apply(output);)+
+                output
+            }
+        }
+    };
+}
+
+impl_transform_for_tuple!(A, B => A; B);
+impl_transform_for_tuple!(A, B => A, C => B; C);
+impl_transform_for_tuple!(A, B => A, C => B, D => C; D);
+impl_transform_for_tuple!(A, B => A, C => B, D => C, E => D; E);
+impl_transform_for_tuple!(A, B => A, C => B, D => C, E => D, F => E; F);
+impl_transform_for_tuple!(A, B => A, C => B, D => C, E => D, F => E, G => F; G);
+impl_transform_for_tuple!(A, B => A, C => B, D => C, E => D, F => E, G => F, H => G; H);
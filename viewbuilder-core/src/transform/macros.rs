@@ -0,0 +1,51 @@
+//! Transform macros.
+
+/// Fuses a list of stages into a single [`BoxedTransform`](crate::transform::BoxedTransform).
+///
+/// Stages are applied in the order they are listed and the fused pipeline maps a type to itself,
+/// see [`Endo`](crate::transform::Endo). Erasing the stages gives the pipeline a nameable type,
+/// whatever the number and types of its stages. An empty list yields the
+/// [`Identity`](crate::transform::Identity) transform.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     pipeline,
+///     transform::*,
+/// };
+///
+/// fn normalize() -> BoxedTransform<f64, f64> {
+///     pipeline![
+///         from_fn(|x: f64| x - 32.0),
+///         from_fn(|x: f64| x * 5.0 / 9.0),
+///         Saturate
+///     ]
+/// }
+///
+/// fn main() {
+///     let empty: BoxedTransform<i32, i32> = pipeline![];
+///     assert_eq!(empty.apply(7), 7);
+///
+///     let double: BoxedTransform<i32, i32> = pipeline![Mul(2)];
+///     assert_eq!(double.apply(7), 14);
+///
+///     assert_eq!(normalize().apply(32.0), 0.0);
+///     assert_eq!(normalize().apply(212.0), 1.0);
+///     assert_eq!(normalize().apply(0.0), 0.0);
+/// }
+/// ```
+#[macro_export]
+macro_rules! pipeline {
+    ($($stage:expr),* $(,)?) => {{
+        fn fuse<Input, Stages>(stages: Stages) -> $crate::transform::BoxedTransform<Input, Input>
+        where
+            Input: Send + Sync,
+            Stages: $crate::transform::Endo<Input> + 'static,
+        {
+            $crate::transform::BoxedTransform::new(stages)
+        }
+
+        let stages = $crate::transform::Identity;
+        $(let stages = $crate::transform::Then::new(stages, $stage);)*
+        fuse(stages)
+    }};
+}
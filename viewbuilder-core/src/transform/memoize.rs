@@ -0,0 +1,171 @@
+//! [`Memoize`](crate::transform::Memoize) definition.
+
+use super::*;
+use std::sync::{
+    Mutex,
+    PoisonError,
+};
+
+/// A transform caching the output of another transform for its last input.
+///
+/// Applying the transform to an input equal to the previous one returns a clone of the cached
+/// output instead of recomputing it, any other input replaces the cache.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::atomic::{
+///     AtomicUsize,
+///     Ordering,
+/// };
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug, Default)]
+/// struct Square(AtomicUsize);
+///
+/// impl OnceTransform<i32> for Square {
+///     type Output = i32;
+///
+///     fn apply_once(self, input: i32) -> Self::Output {
+///         self.apply(input)
+///     }
+/// }
+///
+/// impl MutableTransform<i32> for Square {
+///     fn apply_mut(&mut self, input: i32) -> Self::Output {
+///         self.apply(input)
+///     }
+/// }
+///
+/// impl Transform<i32> for Square {
+///     fn apply(&self, input: i32) -> Self::Output {
+///         let Self(computations) = self;
+///         computations.fetch_add(1, Ordering::SeqCst);
+///         input * input
+///     }
+/// }
+///
+/// fn main() {
+///     let square = Memoize::new(Square::default());
+///     assert_eq!(square.apply(3), 9);
+///     assert_eq!(square.apply(3), 9);
+///     assert_eq!(square.transform().0.load(Ordering::SeqCst), 1);
+///
+///     assert_eq!(square.apply(4), 16);
+///     assert_eq!(square.apply(3), 9);
+///     assert_eq!(square.transform().0.load(Ordering::SeqCst), 3);
+///
+///     square.clear();
+///     assert_eq!(square.apply(3), 9);
+///     assert_eq!(square.transform().0.load(Ordering::SeqCst), 4);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Memoize<TransformType, Input, Output> {
+    transform: TransformType,
+    cache: Mutex<Option<(Input, Output)>>,
+}
+
+impl<TransformType, Input, Output> Memoize<TransformType, Input, Output> {
+    /// Wraps `transform`, starting with an empty cache.
+    pub fn new(transform: TransformType) -> Self {
+        Self {
+            transform,
+            cache: Mutex::new(None),
+        }
+    }
+
+    /// The wrapped transform.
+    pub fn transform(&self) -> &TransformType {
+        &self.transform
+    }
+
+    /// Empties the cache, so the next input is recomputed even if it equals the last one.
+    pub fn clear(&self) {
+        *self.cache.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+impl<TransformType, Input, Output> Memoize<TransformType, Input, Output>
+where
+    Input: PartialEq + Clone,
+    Output: Clone,
+{
+    /// The cached output for `input`, if it is the last input seen.
+    fn cached(&self, input: &Input) -> Option<Output> {
+        let cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        cache
+            .as_ref()
+            .filter(|(cached, _)| cached == input)
+            .map(|(_, output)| output.clone())
+    }
+
+    /// Replaces the cache with the `output` computed for `input`.
+    fn store(&self, input: Input, output: &Output) {
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        *cache = Some((input, output.clone()));
+    }
+}
+
+impl<TransformType, Input, Output> Clone for Memoize<TransformType, Input, Output>
+where
+    TransformType: Clone,
+    Input: Clone,
+    Output: Clone,
+{
+    fn clone(&self) -> Self {
+        let cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        Self {
+            transform: self.transform.clone(),
+            cache: Mutex::new(cache.clone()),
+        }
+    }
+}
+
+impl<TransformType, Input, Output> OnceTransform<Input> for Memoize<TransformType, Input, Output>
+where
+    TransformType: OnceTransform<Input, Output = Output>,
+    Input: PartialEq + Clone + SendSync,
+    Output: Clone + SendSync,
+{
+    type Output = Output;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        match self.cached(&input) {
+            Some(output) => output,
+            None => self.transform.apply_once(input),
+        }
+    }
+}
+
+impl<TransformType, Input, Output> MutableTransform<Input> for Memoize<TransformType, Input, Output>
+where
+    TransformType: MutableTransform<Input, Output = Output>,
+    Input: PartialEq + Clone + SendSync,
+    Output: Clone + SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        if let Some(output) = self.cached(&input) {
+            return output;
+        }
+
+        let output = self.transform.apply_mut(input.clone());
+        self.store(input, &output);
+        output
+    }
+}
+
+impl<TransformType, Input, Output> Transform<Input> for Memoize<TransformType, Input, Output>
+where
+    TransformType: Transform<Input, Output = Output>,
+    Input: PartialEq + Clone + SendSync,
+    Output: Clone + SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        if let Some(output) = self.cached(&input) {
+            return output;
+        }
+
+        let output = self.transform.apply(input.clone());
+        self.store(input, &output);
+        output
+    }
+}
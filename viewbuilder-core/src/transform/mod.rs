@@ -1,9 +1,92 @@
 //! Transform operations traits.
 
+mod boxed;
+mod collection;
+pub mod combinators;
+#[cfg(feature = "serde")]
+pub mod config;
+mod constant;
+mod context;
+mod cow;
+mod ext;
+mod fallible;
+mod fn_transform;
+mod identity;
 mod impls;
+mod macros;
+mod memoize;
+mod num;
+mod ops;
+#[cfg(feature = "time")]
+mod time;
 
 use crate::prelude::*;
 
+pub use boxed::BoxedTransform;
+pub use collection::{
+    DedupAdjacent,
+    First,
+    GroupBy,
+    Last,
+    Pairwise,
+};
+pub use combinators::{
+    AssertInvariant,
+    Branches,
+    Chunked,
+    Discard,
+    EveryNth,
+    Lazy,
+    PassThrough,
+    Pipeline,
+    Sample,
+    ShortCircuit,
+    Sink,
+    Tee,
+    Then,
+    ThenIfContinue,
+};
+pub use constant::Constant;
+pub use context::{
+    ContextualTransform,
+    WithContext,
+};
+pub use cow::{
+    CowTransform,
+    Owned,
+};
+pub use ext::TransformExt;
+pub use fallible::{
+    IntoTry,
+    OrElse,
+    Require,
+    RequireError,
+    TryThen,
+    TryTransform,
+};
+pub use fn_transform::{
+    from_fn,
+    FnTransform,
+};
+pub use identity::Identity;
+pub use memoize::Memoize;
+pub use num::{
+    Add,
+    Fusable,
+    Fuse,
+    Histogram,
+    MovingAverage,
+    Mul,
+    Saturate,
+};
+#[cfg(feature = "time")]
+pub use time::{
+    Clock,
+    Debounce,
+    RateLimit,
+    SystemClock,
+};
+
 /// A transform operation.
 ///
 /// Objects implementing this trait should represent a transform operation over an arbitrary domain.
@@ -54,8 +137,87 @@ where
     /// Apply the transform operation consuming the input and yielding the
     /// output.
     fn apply(&self, input: Input) -> Self::Output;
+
+    /// Apply the transform operation to each of the `inputs`, replacing the content of `out` with
+    /// the outputs.
+    ///
+    /// The buffer keeps its capacity, so reusing it across batches avoids reallocating.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use viewbuilder::transform::*;
+    ///
+    /// fn main() {
+    ///     let double = from_fn(|x: i32| x * 2);
+    ///     let mut out = Vec::with_capacity(8);
+    ///
+    ///     double.apply_into(1..=4, &mut out);
+    ///     assert_eq!(out, vec![2, 4, 6, 8]);
+    ///     let capacity = out.capacity();
+    ///
+    ///     double.apply_into(vec![5, 6], &mut out);
+    ///     assert_eq!(out, vec![10, 12]);
+    ///     assert_eq!(out.capacity(), capacity);
+    /// }
+    /// ```
+    fn apply_into(&self, inputs: impl IntoIterator<Item = Input>, out: &mut Vec<Self::Output>)
+    where
+        Self: Sized,
+    {
+        out.clear();
+        out.extend(inputs.into_iter().map(|input| self.apply(input)));
+    }
+
+    /// Applies the transform repeatedly, starting from `initial`, until its output stops changing
+    /// or `max_iters` applications were made.
+    ///
+    /// Returns the fixed point reached, or the last output when the cap is hit first.
+    /// ```
+    /// # use viewbuilder_core as viewbuilder;
+    /// use std::sync::atomic::{
+    ///     AtomicUsize,
+    ///     Ordering,
+    /// };
+    /// use viewbuilder::transform::*;
+    ///
+    /// fn main() {
+    ///     let halve = from_fn(|x: u32| x / 2);
+    ///     assert_eq!(halve.converge(100, 20), 0);
+    ///     assert_eq!(halve.converge(100, 3), 12);
+    ///     assert_eq!(halve.converge(100, 0), 100);
+    ///
+    ///     let applications = AtomicUsize::new(0);
+    ///     let counted = from_fn(|x: u32| {
+    ///         applications.fetch_add(1, Ordering::SeqCst);
+    ///         x / 2
+    ///     });
+    ///     assert_eq!(counted.converge(100, 20), 0);
+    ///     // Halving 100, 50, 25, 12, 6, 3, 1 and 0, which yields 0 again and ends the iteration.
+    ///     assert_eq!(applications.load(Ordering::SeqCst), 8);
+    /// }
+    /// ```
+    fn converge(&self, initial: Input, max_iters: usize) -> Input
+    where
+        Self: Transform<Input, Output = Input> + Sized,
+        Input: PartialEq + Clone,
+    {
+        let mut current = initial;
+        for _ in 0..max_iters {
+            let next = self.apply(current.clone());
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+        current
+    }
 }
 
+/// A transform operation mapping a type to itself.
+///
+/// Endomorphisms can be applied any number of times in a row, as with
+/// [`converge`](Transform::converge) or the stages of a [`Pipeline`].
+pub trait Endo<Input> = Transform<Input, Output = Input> where Input: SendSync;
+
 /// A mutable transform operation.
 ///
 ///
@@ -145,3 +307,68 @@ where
     /// output.
     fn apply_once(self, input: Input) -> Self::Output;
 }
+
+/// A transform operation that can be undone.
+///
+/// The [`inverse`](InversibleTransform::inverse) transform maps the outputs of this transform back
+/// to their inputs.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let add2 = Add(2);
+///     let a = add2.apply(1);
+///     println!("{a}");
+/// # assert_eq!(a, 3);
+///     let b = add2.inverse().apply(a);
+///     println!("{b}");
+/// # assert_eq!(b, 1);
+/// }
+/// ```
+pub trait InversibleTransform<Input>
+where
+    Self: Transform<Input>,
+    Input: SendSync,
+{
+    /// The transform undoing this one.
+    type Inverse: Transform<Self::Output, Output = Input>;
+
+    /// Creates the transform undoing this one.
+    fn inverse(&self) -> Self::Inverse;
+}
+
+/// A transform operation whose outputs can be reverted to their inputs.
+///
+/// Every [`InversibleTransform`] is revertable by applying its inverse.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let add2 = Add(2);
+///     let a = add2.apply(1);
+///     let b = add2.revert(a);
+///     println!("{b}");
+/// # assert_eq!(b, 1);
+/// }
+/// ```
+pub trait RevertableTransform<Input>
+where
+    Self: Transform<Input>,
+    Input: SendSync,
+{
+    /// Revert the output of the transform operation back to the input it was applied to.
+    fn revert(&self, output: Self::Output) -> Input;
+}
+
+impl<Input, TransformType> RevertableTransform<Input> for TransformType
+where
+    TransformType: InversibleTransform<Input>,
+    TransformType::Inverse: Transform<TransformType::Output, Output = Input>,
+    Input: SendSync,
+{
+    fn revert(&self, output: Self::Output) -> Input {
+        self.inverse().apply(output)
+    }
+}
@@ -0,0 +1,436 @@
+//! Numeric transform operations.
+
+use super::*;
+use crate::layout::{
+    Position,
+    Vector,
+};
+use std::{
+    collections::VecDeque,
+    ops::{
+        self,
+        RangeInclusive,
+    },
+};
+
+/// A transform adding a value to its input.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::Position,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     assert_eq!(Add(2).apply(40), 42);
+///     assert_eq!(Add(0.5).apply(1.0), 1.5);
+///     assert_eq!(Add(2).inverse().apply(42), 40);
+///     assert_eq!(
+///         Add(Position::new(1.0, 2.0)).apply(Position::new(3.0, 4.0)),
+///         Position::new(4.0, 6.0)
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Add<Value>(pub Value);
+
+impl<Value> OnceTransform<Value> for Add<Value>
+where
+    Value: ops::Add<Output = Value> + Clone + SendSync,
+{
+    type Output = Value;
+
+    fn apply_once(self, input: Value) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Value> MutableTransform<Value> for Add<Value>
+where
+    Value: ops::Add<Output = Value> + Clone + SendSync,
+{
+    fn apply_mut(&mut self, input: Value) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Value> Transform<Value> for Add<Value>
+where
+    Value: ops::Add<Output = Value> + Clone + SendSync,
+{
+    fn apply(&self, input: Value) -> Self::Output {
+        input + self.0.clone()
+    }
+}
+
+impl<Value> InversibleTransform<Value> for Add<Value>
+where
+    Value: ops::Add<Output = Value> + ops::Neg<Output = Value> + Clone + SendSync,
+{
+    type Inverse = Self;
+
+    fn inverse(&self) -> Self::Inverse {
+        Add(-self.0.clone())
+    }
+}
+
+/// A transform multiplying its input by a value.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     assert_eq!(Mul(2).apply(21), 42);
+///     assert_eq!(Mul(0.5).apply(3.0), 1.5);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Mul<Value>(pub Value);
+
+impl<Value> OnceTransform<Value> for Mul<Value>
+where
+    Value: ops::Mul<Output = Value> + Clone + SendSync,
+{
+    type Output = Value;
+
+    fn apply_once(self, input: Value) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Value> MutableTransform<Value> for Mul<Value>
+where
+    Value: ops::Mul<Output = Value> + Clone + SendSync,
+{
+    fn apply_mut(&mut self, input: Value) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl<Value> Transform<Value> for Mul<Value>
+where
+    Value: ops::Mul<Output = Value> + Clone + SendSync,
+{
+    fn apply(&self, input: Value) -> Self::Output {
+        input * self.0.clone()
+    }
+}
+
+/// Arithmetic transforms merging with a following transform of the same kind.
+pub trait Fusable
+where
+    Self: Sized,
+{
+    /// Merges the transform with the `next` one into a single transform equivalent to applying
+    /// both in order.
+    fn fuse_with(self, next: Self) -> Self;
+}
+
+impl<Value> Fusable for Add<Value>
+where
+    Value: ops::Add<Output = Value>,
+{
+    fn fuse_with(self, next: Self) -> Self {
+        Add(self.0 + next.0)
+    }
+}
+
+impl<Value> Fusable for Mul<Value>
+where
+    Value: ops::Mul<Output = Value>,
+{
+    fn fuse_with(self, next: Self) -> Self {
+        Mul(self.0 * next.0)
+    }
+}
+
+/// Transforms collapsing into a single equivalent transform.
+///
+/// Chains of [`Add`] transforms fuse into a single [`Add`] of the sum of their values, and chains
+/// of [`Mul`] transforms into a single [`Mul`] of their product, removing the indirection of each
+/// [`Then`] stage. Chains mixing both kinds do not fuse.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let chain = Add(1).then(Add(2)).then(Add(3));
+///     let fused = chain.fuse();
+///     assert_eq!(fused, Add(6));
+///     for input in -10..10 {
+///         assert_eq!(fused.apply(input), chain.apply(input));
+///     }
+///
+///     let scale = Mul(2.0).then(Mul(1.5).then(Mul(4.0)));
+///     assert_eq!(scale.fuse(), Mul(12.0));
+///     assert_eq!(scale.fuse().apply(1.0), scale.apply(1.0));
+/// }
+/// ```
+pub trait Fuse
+where
+    Self: Sized,
+{
+    /// The single transform the chain collapses into.
+    type Fused: Fusable;
+
+    /// Collapses the chain into a single equivalent transform.
+    fn fuse(self) -> Self::Fused;
+}
+
+impl<Value> Fuse for Add<Value>
+where
+    Value: ops::Add<Output = Value>,
+{
+    type Fused = Self;
+
+    fn fuse(self) -> Self::Fused {
+        self
+    }
+}
+
+impl<Value> Fuse for Mul<Value>
+where
+    Value: ops::Mul<Output = Value>,
+{
+    type Fused = Self;
+
+    fn fuse(self) -> Self::Fused {
+        self
+    }
+}
+
+impl<First, Next> Fuse for Then<First, Next>
+where
+    First: Fuse,
+    Next: Fuse<Fused = First::Fused>,
+{
+    type Fused = First::Fused;
+
+    fn fuse(self) -> Self::Fused {
+        let (first, next) = self.into_parts();
+        first.fuse().fuse_with(next.fuse())
+    }
+}
+
+/// A transform clamping normalized values to the `[0.0, 1.0]` range.
+///
+/// [`Position`] and [`Vector`] inputs are clamped component-wise. As with [`f64::clamp`], `NaN`
+/// values are left as is.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::Position,
+///     transform::*,
+/// };
+///
+/// fn main() {
+///     assert_eq!(Saturate.apply(-0.5), 0.0);
+///     assert_eq!(Saturate.apply(1.5), 1.0);
+///     assert_eq!(Saturate.apply(0.5), 0.5);
+///     assert_eq!(
+///         Saturate.apply(Position::new(-0.5, 1.5)),
+///         Position::new(0.0, 1.0)
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Saturate;
+
+impl OnceTransform<f64> for Saturate {
+    type Output = f64;
+
+    fn apply_once(self, input: f64) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<f64> for Saturate {
+    fn apply_mut(&mut self, input: f64) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<f64> for Saturate {
+    fn apply(&self, input: f64) -> Self::Output {
+        input.clamp(0.0, 1.0)
+    }
+}
+
+impl OnceTransform<Position> for Saturate {
+    type Output = Position;
+
+    fn apply_once(self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Position> for Saturate {
+    fn apply_mut(&mut self, input: Position) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Position> for Saturate {
+    fn apply(&self, input: Position) -> Self::Output {
+        Position::new(self.apply(input.x()), self.apply(input.y()))
+    }
+}
+
+impl OnceTransform<Vector> for Saturate {
+    type Output = Vector;
+
+    fn apply_once(self, input: Vector) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Vector> for Saturate {
+    fn apply_mut(&mut self, input: Vector) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Vector> for Saturate {
+    fn apply(&self, input: Vector) -> Self::Output {
+        Vector::new(self.apply(input.x()), self.apply(input.y()))
+    }
+}
+
+/// A transform counting how many values fall in each of a number of equal-width bins.
+///
+/// The bins evenly split the range, each including its lower bound, the last one including the
+/// upper bound of the range as well. Values outside of the range are clamped to the edge bins and
+/// `NaN` values are not counted.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let histogram = Histogram::new(2, 0.0..=1.0);
+///     assert_eq!(histogram.apply(vec![0.0, 0.5, 1.0]), vec![1, 2]);
+///     assert_eq!(histogram.apply(vec![-3.0, 0.2, 7.0, f64::NAN]), vec![2, 1]);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    bins: usize,
+    range: RangeInclusive<f64>,
+}
+
+impl Histogram {
+    /// Creates a transform counting values in `bins` equal-width bins splitting the `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is 0.
+    pub fn new(bins: usize, range: RangeInclusive<f64>) -> Self {
+        assert!(bins != 0, "bin count must be non zero");
+        Self { bins, range }
+    }
+
+    /// The number of bins.
+    pub fn bins(&self) -> usize {
+        self.bins
+    }
+
+    /// The range split by the bins.
+    pub fn range(&self) -> &RangeInclusive<f64> {
+        &self.range
+    }
+
+    /// The index of the bin counting `value`.
+    fn bin(&self, value: f64) -> usize {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let position = (value - start) / (end - start) * self.bins as f64;
+        // `as` saturates, clamping values below the range to the first bin.
+        (position.max(0.0) as usize).min(self.bins - 1)
+    }
+}
+
+impl OnceTransform<Vec<f64>> for Histogram {
+    type Output = Vec<u64>;
+
+    fn apply_once(self, input: Vec<f64>) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl MutableTransform<Vec<f64>> for Histogram {
+    fn apply_mut(&mut self, input: Vec<f64>) -> Self::Output {
+        self.apply(input)
+    }
+}
+
+impl Transform<Vec<f64>> for Histogram {
+    fn apply(&self, input: Vec<f64>) -> Self::Output {
+        let mut counts = vec![0; self.bins];
+        for value in input.into_iter().filter(|value| !value.is_nan()) {
+            counts[self.bin(value)] += 1;
+        }
+        counts
+    }
+}
+
+/// A transform smoothing a stream of values with their moving average.
+///
+/// Each call records its input and yields the mean of the last `window` inputs. During the
+/// warm-up, while fewer than `window` inputs have been seen, the mean of all the inputs seen so
+/// far is yielded instead. Being stateful, the transform is only a [`MutableTransform`].
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::transform::*;
+///
+/// fn main() {
+///     let mut average = MovingAverage::new(2);
+///     let outputs: Vec<_> = vec![1.0, 2.0, 3.0, 4.0]
+///         .into_iter()
+///         .map(|input| average.apply_mut(input))
+///         .collect();
+///     println!("{outputs:?}");
+/// # assert_eq!(outputs, vec![1.0, 1.5, 2.5, 3.5]);
+/// # assert_eq!(average.window(), 2);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovingAverage {
+    window: usize,
+    samples: VecDeque<f64>,
+}
+
+impl MovingAverage {
+    /// Creates a transform averaging the last `window` inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    pub fn new(window: usize) -> Self {
+        assert!(window != 0, "moving average window must be non zero");
+        Self {
+            window,
+            samples: VecDeque::with_capacity(window),
+        }
+    }
+
+    /// The number of inputs averaged once warmed up.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+}
+
+impl OnceTransform<f64> for MovingAverage {
+    type Output = f64;
+
+    fn apply_once(mut self, input: f64) -> Self::Output {
+        self.apply_mut(input)
+    }
+}
+
+impl MutableTransform<f64> for MovingAverage {
+    fn apply_mut(&mut self, input: f64) -> Self::Output {
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(input);
+        self.samples.iter().sum::<f64>() / self.samples.len() as f64
+    }
+}
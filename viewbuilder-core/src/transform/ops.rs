@@ -0,0 +1,92 @@
+//! Operator overloads of the transforms.
+//!
+//! `first >> next` chains two transforms into a [`Then`], feeding the output of `first` to
+//! `next`. The operator points in the direction the data flows, so pipelines read left to right;
+//! `|` was ruled out as it suggests alternatives rather than a sequence.
+//!
+//! The orphan rule forbids implementing [`Shr`] for every transform at once, so it is implemented
+//! for each of the transforms of this crate, and a custom transform can opt in with an
+//! implementation equivalent to [`TransformExt::then`]. Whether both transforms chain is checked
+//! when the composition is applied, and numeric types keep their own `>>`.
+//! ```
+//! # use viewbuilder_core as viewbuilder;
+//! use viewbuilder::transform::*;
+//!
+//! fn main() {
+//!     assert_eq!((Add(1) >> Add(2)).apply(0), 3);
+//!     assert_eq!(
+//!         (Identity >> Add(1) >> from_fn(|x: i32| x * 10)).apply(0),
+//!         10
+//!     );
+//!     assert_eq!(8 >> 2, 2);
+//! }
+//! ```
+
+use super::*;
+use crate::{
+    color::{
+        Darken,
+        Lighten,
+        Opacity,
+    },
+    layout::{
+        AffineTransform,
+        Rotate,
+        Scale,
+        Translate,
+    },
+};
+use std::ops::Shr;
+
+macro_rules! impl_shr {
+    ($type:ty $(, [$($param:ident),*])? $(where $($bound:tt)+)?) => {
+        impl<$($($param,)*)? Rhs> Shr<Rhs> for $type $(where $($bound)+)? {
+            type Output = Then<Self, Rhs>;
+
+            fn shr(self, next: Rhs) -> Self::Output {
+                Then::new(self, next)
+            }
+        }
+    };
+}
+
+impl_shr!(Add<Value>, [Value]);
+impl_shr!(AffineTransform);
+impl_shr!(AssertInvariant<TransformType, Invariant>, [TransformType, Invariant]);
+impl_shr!(BoxedTransform<Input, Output>, [Input, Output]);
+impl_shr!(Branches<TransformType>, [TransformType]);
+impl_shr!(Chunked<TransformType>, [TransformType]);
+impl_shr!(Constant<Value>, [Value]);
+impl_shr!(Darken);
+impl_shr!(DedupAdjacent);
+impl_shr!(EveryNth<TransformType, Skipped>, [TransformType, Skipped]);
+impl_shr!(First<Predicate>, [Predicate]);
+impl_shr!(FnTransform<Closure>, [Closure]);
+impl_shr!(GroupBy<KeyFn>, [KeyFn]);
+impl_shr!(Histogram);
+impl_shr!(Identity);
+impl_shr!(Last<Predicate>, [Predicate]);
+impl_shr!(Lazy<Builder, TransformType>, [Builder, TransformType]);
+impl_shr!(Lighten);
+impl_shr!(Memoize<TransformType, Input, Output>, [TransformType, Input, Output]);
+impl_shr!(MovingAverage);
+impl_shr!(Mul<Value>, [Value]);
+impl_shr!(Opacity);
+impl_shr!(OrElse<Primary, Fallback>, [Primary, Fallback]);
+impl_shr!(Owned<TransformType, Input>, [TransformType, Input] where Input: ToOwned + ?Sized);
+impl_shr!(Pairwise<Combine>, [Combine]);
+impl_shr!(Pipeline<TransformType, Input>, [TransformType, Input]);
+impl_shr!(Rotate);
+impl_shr!(Sample<TransformType>, [TransformType]);
+impl_shr!(Saturate);
+impl_shr!(Scale);
+impl_shr!(ShortCircuit<TransformType>, [TransformType]);
+impl_shr!(Tee<Input>, [Input]);
+impl_shr!(Then<First, Next>, [First, Next]);
+impl_shr!(ThenIfContinue<First, Next>, [First, Next]);
+impl_shr!(Translate);
+impl_shr!(WithContext<TransformType, Context>, [TransformType, Context]);
+#[cfg(feature = "time")]
+impl_shr!(Debounce<TransformType, ClockType>, [TransformType, ClockType]);
+#[cfg(feature = "time")]
+impl_shr!(RateLimit<TransformType, ClockType>, [TransformType, ClockType]);
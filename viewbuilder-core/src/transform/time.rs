@@ -0,0 +1,359 @@
+//! Time based transform operations.
+//!
+//! Only available with the `time` feature.
+
+use super::*;
+use std::{
+    sync::{
+        Mutex,
+        PoisonError,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// A source of the current time.
+///
+/// Time based transforms read the time through a clock so it can be controlled in tests.
+pub trait Clock
+where
+    Self: SendSync,
+{
+    /// The current instant.
+    fn now(&self) -> Instant;
+}
+
+/// The [`Clock`] reading the system's monotonic time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A transform applying another transform at most once per interval.
+///
+/// An input is transformed only when the interval elapsed since the last time the inner transform
+/// was applied, the first input always being transformed. Inputs arriving sooner are dropped: the
+/// transform yields `None` for them and does not restart the interval.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::{
+///     sync::{
+///         Arc,
+///         Mutex,
+///     },
+///     time::{
+///         Duration,
+///         Instant,
+///     },
+/// };
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug, Clone)]
+/// struct MockClock(Arc<Mutex<Instant>>);
+///
+/// impl MockClock {
+///     fn advance(&self, duration: Duration) {
+///         *self.0.lock().unwrap() += duration;
+///     }
+/// }
+///
+/// impl Clock for MockClock {
+///     fn now(&self) -> Instant {
+///         *self.0.lock().unwrap()
+///     }
+/// }
+///
+/// fn main() {
+///     let clock = MockClock(Arc::new(Mutex::new(Instant::now())));
+///     let debounce = Debounce::with_clock(Mul(2), Duration::from_millis(100), clock.clone());
+///
+///     assert_eq!(debounce.apply(1), Some(2));
+///     clock.advance(Duration::from_millis(30));
+///     assert_eq!(debounce.apply(2), None);
+///     clock.advance(Duration::from_millis(30));
+///     assert_eq!(debounce.apply(3), None);
+///     clock.advance(Duration::from_millis(40));
+///     assert_eq!(debounce.apply(4), Some(8));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Debounce<TransformType, ClockType = SystemClock> {
+    transform: TransformType,
+    interval: Duration,
+    clock: ClockType,
+    last: Mutex<Option<Instant>>,
+}
+
+impl<TransformType> Debounce<TransformType> {
+    /// Creates a transform applying `transform` at most once per `interval` of system time.
+    pub fn new(transform: TransformType, interval: Duration) -> Self {
+        Self::with_clock(transform, interval, SystemClock)
+    }
+}
+
+impl<TransformType, ClockType> Debounce<TransformType, ClockType>
+where
+    ClockType: Clock,
+{
+    /// Creates a transform applying `transform` at most once per `interval` measured by `clock`.
+    pub fn with_clock(transform: TransformType, interval: Duration, clock: ClockType) -> Self {
+        Self {
+            transform,
+            interval,
+            clock,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// The minimal time between two applications of the inner transform.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Whether the inner transform can be applied now, restarting the interval if so.
+    fn ready(&self) -> bool {
+        let now = self.clock.now();
+        let mut last = self.last.lock().unwrap_or_else(PoisonError::into_inner);
+        match *last {
+            Some(instant) if now.saturating_duration_since(instant) < self.interval => false,
+            _ => {
+                *last = Some(now);
+                true
+            }
+        }
+    }
+}
+
+impl<Input, TransformType, ClockType> OnceTransform<Input> for Debounce<TransformType, ClockType>
+where
+    TransformType: OnceTransform<Input>,
+    ClockType: Clock,
+    Input: SendSync,
+{
+    type Output = Option<TransformType::Output>;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        self.ready().then(|| self.transform.apply_once(input))
+    }
+}
+
+impl<Input, TransformType, ClockType> MutableTransform<Input> for Debounce<TransformType, ClockType>
+where
+    TransformType: MutableTransform<Input>,
+    ClockType: Clock,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.ready().then(|| self.transform.apply_mut(input))
+    }
+}
+
+impl<Input, TransformType, ClockType> Transform<Input> for Debounce<TransformType, ClockType>
+where
+    TransformType: Transform<Input>,
+    ClockType: Clock,
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        self.ready().then(|| self.transform.apply(input))
+    }
+}
+
+/// The tokens left in the bucket of a [`RateLimit`].
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled: Option<Instant>,
+}
+
+/// A transform applying another transform only while a token-bucket allows it.
+///
+/// The bucket starts full with `capacity` tokens and refills continuously at `refill_rate` tokens
+/// per second, never holding more than its capacity. Each input consumes a token to be
+/// transformed, inputs arriving while the bucket holds less than a whole token are passed through
+/// unchanged. Bursts of up to `capacity` inputs are thus transformed, then the transform is
+/// applied at the refill rate.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::{
+///     sync::{
+///         Arc,
+///         Mutex,
+///     },
+///     time::{
+///         Duration,
+///         Instant,
+///     },
+/// };
+/// use viewbuilder::transform::*;
+///
+/// #[derive(Debug, Clone)]
+/// struct MockClock(Arc<Mutex<Instant>>);
+///
+/// impl MockClock {
+///     fn advance(&self, duration: Duration) {
+///         *self.0.lock().unwrap() += duration;
+///     }
+/// }
+///
+/// impl Clock for MockClock {
+///     fn now(&self) -> Instant {
+///         *self.0.lock().unwrap()
+///     }
+/// }
+///
+/// fn main() {
+///     let clock = MockClock(Arc::new(Mutex::new(Instant::now())));
+///     let limit = RateLimit::with_clock(Mul(2), 2, 10.0, clock.clone());
+///
+///     let burst: Vec<_> = (1..=3).map(|input| limit.apply(input)).collect();
+///     println!("{burst:?}");
+/// # assert_eq!(burst, vec![2, 4, 3]);
+///
+///     clock.advance(Duration::from_millis(50));
+///     assert_eq!(limit.apply(4), 4);
+///     clock.advance(Duration::from_millis(50));
+///     assert_eq!(limit.apply(5), 10);
+///     assert_eq!(limit.apply(6), 6);
+///
+///     clock.advance(Duration::from_secs(10));
+///     let burst: Vec<_> = (1..=3).map(|input| limit.apply(input)).collect();
+/// # assert_eq!(burst, vec![2, 4, 3]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RateLimit<TransformType, ClockType = SystemClock> {
+    transform: TransformType,
+    capacity: u32,
+    refill_rate: f64,
+    clock: ClockType,
+    bucket: Mutex<Bucket>,
+}
+
+impl<TransformType> RateLimit<TransformType> {
+    /// Creates a transform applying `transform` while a bucket of `capacity` tokens, refilled at
+    /// `refill_rate` tokens per second of system time, is not empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0 or if `refill_rate` is negative or not finite.
+    pub fn new(transform: TransformType, capacity: u32, refill_rate: f64) -> Self {
+        Self::with_clock(transform, capacity, refill_rate, SystemClock)
+    }
+}
+
+impl<TransformType, ClockType> RateLimit<TransformType, ClockType>
+where
+    ClockType: Clock,
+{
+    /// Creates a transform applying `transform` while a bucket of `capacity` tokens, refilled at
+    /// `refill_rate` tokens per second measured by `clock`, is not empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0 or if `refill_rate` is negative or not finite.
+    pub fn with_clock(
+        transform: TransformType,
+        capacity: u32,
+        refill_rate: f64,
+        clock: ClockType,
+    ) -> Self {
+        assert!(capacity != 0, "rate limit capacity must be non zero");
+        assert!(
+            refill_rate.is_finite() && refill_rate >= 0.0,
+            "rate limit refill rate must be finite and non negative"
+        );
+        Self {
+            transform,
+            capacity,
+            refill_rate,
+            clock,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(capacity),
+                refilled: None,
+            }),
+        }
+    }
+
+    /// The maximal number of tokens in the bucket.
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// The number of tokens added to the bucket per second.
+    pub fn refill_rate(&self) -> f64 {
+        self.refill_rate
+    }
+
+    /// Refills the bucket, then consumes a token if there is one.
+    fn acquire(&self) -> bool {
+        let now = self.clock.now();
+        let mut bucket = self.bucket.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(refilled) = bucket.refilled {
+            let elapsed = now.saturating_duration_since(refilled).as_secs_f64();
+            bucket.tokens =
+                (bucket.tokens + elapsed * self.refill_rate).min(f64::from(self.capacity));
+        }
+        bucket.refilled = Some(now);
+
+        if bucket.tokens < 1.0 {
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+}
+
+impl<Input, TransformType, ClockType> OnceTransform<Input> for RateLimit<TransformType, ClockType>
+where
+    TransformType: OnceTransform<Input, Output = Input>,
+    ClockType: Clock,
+    Input: SendSync,
+{
+    type Output = Input;
+
+    fn apply_once(self, input: Input) -> Self::Output {
+        if self.acquire() {
+            self.transform.apply_once(input)
+        } else {
+            input
+        }
+    }
+}
+
+impl<Input, TransformType, ClockType> MutableTransform<Input>
+    for RateLimit<TransformType, ClockType>
+where
+    TransformType: MutableTransform<Input, Output = Input>,
+    ClockType: Clock,
+    Input: SendSync,
+{
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        if self.acquire() {
+            self.transform.apply_mut(input)
+        } else {
+            input
+        }
+    }
+}
+
+impl<Input, TransformType, ClockType> Transform<Input> for RateLimit<TransformType, ClockType>
+where
+    TransformType: Transform<Input, Output = Input>,
+    ClockType: Clock,
+    Input: SendSync,
+{
+    fn apply(&self, input: Input) -> Self::Output {
+        if self.acquire() {
+            self.transform.apply(input)
+        } else {
+            input
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! [`Either`](crate::view::Either) definition.
+
+use super::*;
+
+/// A view presenting one of two views, chosen at runtime.
+///
+/// Lets the branches of a conditional return a common type.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     view::{
+///         Either,
+///         EmptyView,
+///         Text,
+///     },
+///     View,
+/// };
+///
+/// fn status(online: bool) -> Either<Text, Text> {
+///     if online {
+///         Either::left(Text::new("Online"))
+///     } else {
+///         Either::right(Text::new("Offline"))
+///     }
+/// }
+///
+/// fn main() {
+///     let view = status(false);
+///     assert!(view.is_right());
+///     assert_eq!(view, Either::Right(Text::new("Offline")));
+///     assert_eq!(status(true).body(), Either::Left(EmptyView));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<Left, Right> {
+    /// The first view.
+    Left(Left),
+    /// The second view.
+    Right(Right),
+}
+
+impl<Left, Right> Either<Left, Right> {
+    /// Creates a view presenting the `left` view.
+    pub fn left(left: Left) -> Self {
+        Self::Left(left)
+    }
+
+    /// Creates a view presenting the `right` view.
+    pub fn right(right: Right) -> Self {
+        Self::Right(right)
+    }
+
+    /// Whether the first view is presented.
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    /// Whether the second view is presented.
+    pub fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+}
+
+impl<Left, Right> View for Either<Left, Right>
+where
+    Left: View,
+    Right: View,
+{
+    type Body = Either<Left::Body, Right::Body>;
+
+    fn body(&self) -> Self::Body {
+        match self {
+            Self::Left(left) => Either::Left(left.body()),
+            Self::Right(right) => Either::Right(right.body()),
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        match self {
+            Self::Left(left) => left.collect_preferences(preferences),
+            Self::Right(right) => right.collect_preferences(preferences),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! [`EmptyView`](crate::view::EmptyView) definition.
+
+use super::*;
+
+/// A view displaying nothing.
+///
+/// Stands for empty slots and defaults, reading more clearly at call sites than `()`.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     view::{
+///         Either,
+///         EmptyView,
+///         Text,
+///     },
+///     View,
+/// };
+///
+/// fn footer() -> impl View {
+///     EmptyView
+/// }
+///
+/// fn badge(count: usize) -> Either<Text, EmptyView> {
+///     if count == 0 {
+///         Either::right(EmptyView)
+///     } else {
+///         Either::left(Text::new(count.to_string()))
+///     }
+/// }
+///
+/// fn main() {
+///     let _footer = footer().body();
+///     assert_eq!(EmptyView.body(), EmptyView::default());
+///     assert_eq!(badge(0).body(), Either::Right(EmptyView));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EmptyView;
+
+impl View for EmptyView {
+    type Body = Self;
+
+    fn body(&self) -> Self::Body {
+        Self
+    }
+
+    fn collect_preferences(&self, _preferences: &mut PreferenceValues) {}
+}
@@ -0,0 +1,106 @@
+//! Values flowing down the view hierarchy.
+//!
+//! Ancestors inject values with the [`environment`](crate::view::ViewExt::environment) modifier
+//! and descendants read them from the resolved [`EnvironmentValues`].
+
+use super::*;
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// A key identifying a value stored in the [`EnvironmentValues`].
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::environment::*,
+/// };
+///
+/// struct Spacing;
+///
+/// impl EnvironmentKey for Spacing {
+///     type Value = f64;
+///
+///     const DEFAULT: Self::Value = 8.0;
+/// }
+///
+/// fn main() {
+///     let environment = EnvironmentValues::new();
+///     assert_eq!(environment.get::<Spacing>(), 8.0);
+///
+///     let view = ().environment::<Spacing>(16.0);
+///     let environment = view.resolve_environment(&environment);
+///     assert_eq!(environment.get::<Spacing>(), 16.0);
+/// }
+/// ```
+pub trait EnvironmentKey
+where
+    Self: 'static,
+{
+    /// The type of the value identified by the key.
+    type Value: Clone + SendSync;
+
+    /// The value read when none was injected.
+    const DEFAULT: Self::Value;
+}
+
+/// The environment values visible to a view.
+#[derive(Clone, Default)]
+pub struct EnvironmentValues {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl EnvironmentValues {
+    /// Creates an environment where every key has its default value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of `Key`, or [`EnvironmentKey::DEFAULT`] when it was never injected.
+    pub fn get<Key>(&self) -> Key::Value
+    where
+        Key: EnvironmentKey,
+    {
+        self.values
+            .get(&TypeId::of::<Key>())
+            .and_then(|value| value.downcast_ref::<Key::Value>())
+            .cloned()
+            .unwrap_or(Key::DEFAULT)
+    }
+
+    /// Whether a value was injected for `Key`.
+    pub fn contains<Key>(&self) -> bool
+    where
+        Key: EnvironmentKey,
+    {
+        self.values.contains_key(&TypeId::of::<Key>())
+    }
+
+    /// Injects the `value` of `Key`, replacing any previous one.
+    pub fn set<Key>(&mut self, value: Key::Value)
+    where
+        Key: EnvironmentKey,
+    {
+        self.values.insert(TypeId::of::<Key>(), Arc::new(value));
+    }
+}
+
+impl Debug for EnvironmentValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnvironmentValues")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+/// Views adjusting the environment of their content.
+pub trait ResolveEnvironment {
+    /// The environment seen by the content given the `parent` environment.
+    fn resolve_environment(&self, parent: &EnvironmentValues) -> EnvironmentValues;
+}
@@ -0,0 +1,261 @@
+//! [`ViewExt`](crate::view::ViewExt) definition.
+
+use super::{
+    environment::EnvironmentKey,
+    modifiers::*,
+    preference::PreferenceKey,
+    *,
+};
+use crate::layout::{
+    EdgeInsets,
+    Guide,
+    Rect,
+    Size,
+};
+
+/// Modifiers available on every [`View`].
+///
+/// Each modifier consumes the view and wraps it into a new view carrying the modifier's data.
+pub trait ViewExt
+where
+    Self: View + Sized,
+{
+    /// Overrides the `guide` of the view with a value computed from its size.
+    fn alignment_guide<Compute>(
+        self,
+        guide: impl Into<Guide>,
+        compute: Compute,
+    ) -> AlignmentGuide<Self, Compute>
+    where
+        Compute: Fn(Size) -> f64,
+    {
+        AlignmentGuide::new(self, guide.into(), compute)
+    }
+
+    /// Sets whether the view's subtree receives pointer events.
+    ///
+    /// A disabled subtree lets hits fall through to the views below, see [`hit_test`].
+    fn allows_hit_testing(self, enabled: bool) -> AllowsHitTesting<Self> {
+        AllowsHitTesting::new(self, enabled)
+    }
+
+    /// Composites the view's subtree into a single layer before group-level effects apply.
+    fn compositing_group(self) -> CompositingGroup<Self> {
+        CompositingGroup::new(self)
+    }
+
+    /// Fills the container named `for_container` with the `background` view.
+    fn container_background<Background>(
+        self,
+        background: Background,
+        for_container: &'static str,
+    ) -> ContainerBackground<Self, Background>
+    where
+        Background: View,
+    {
+        ContainerBackground::new(self, background, for_container)
+    }
+
+    /// Hints that the view's subtree should be rasterized as a single unit.
+    fn drawing_group(self) -> DrawingGroup<Self> {
+        DrawingGroup::new(self)
+    }
+
+    /// Marks the view as skippable when re-evaluated with an equal value.
+    ///
+    /// Changes are detected by [`Equatable::should_update`].
+    fn equatable(self) -> Equatable<Self>
+    where
+        Self: PartialEq,
+    {
+        Equatable::new(self)
+    }
+
+    /// Injects the `value` of `Key` in the environment of the view's content.
+    fn environment<Key>(self, value: Key::Value) -> WithEnvironment<Self, Key>
+    where
+        Key: EnvironmentKey,
+    {
+        WithEnvironment::new(self, value)
+    }
+
+    /// Makes the view able to receive the keyboard focus, or not.
+    fn focusable(self, is_focusable: bool) -> Focusable<Self> {
+        Focusable::new(self, is_focusable)
+    }
+
+    /// Binds the view to the focus `binding` under the `id`.
+    ///
+    /// Focusable views move the focus between them through a [`FocusOrder`].
+    fn focused(self, id: FocusId, binding: FocusState) -> Focused<Self> {
+        Focused::new(self, id, binding)
+    }
+
+    /// Calls `probe` with the view whenever its body is evaluated, leaving the body unchanged.
+    fn inspect<Probe>(self, probe: Probe) -> Inspect<Self, Probe>
+    where
+        Probe: Fn(&Self),
+    {
+        Inspect::new(self, probe)
+    }
+
+    /// Limits the text of the view to `limit` lines, `None` leaving it unlimited.
+    fn line_limit(self, limit: Option<usize>) -> LineLimit<Self> {
+        LineLimit::new(self, limit)
+    }
+
+    /// Sets the [`ListStyle`] of the lists within the view.
+    fn list_style(self, style: ListStyle) -> WithEnvironment<Self, ListStyle> {
+        WithEnvironment::new(self, style)
+    }
+
+    /// Tags the view as the source or target of a shared element transition.
+    ///
+    /// Views sharing an `id` within a `namespace` are paired by a [`MatchedGeometryRegistry`].
+    fn matched_geometry(
+        self,
+        id: impl Into<String>,
+        namespace: &'static str,
+    ) -> MatchedGeometry<Self> {
+        MatchedGeometry::new(self, id.into(), namespace)
+    }
+
+    /// Sets the title shown by the enclosing navigation container.
+    ///
+    /// The title is reported through the [`NavigationTitleKey`] preference.
+    fn navigation_title(
+        self,
+        title: impl Into<String>,
+    ) -> WithPreference<Self, NavigationTitleKey> {
+        WithPreference::new(self, title.into())
+    }
+
+    /// Calls `handler` with the state of the drag gestures over the view.
+    fn on_drag<Handler>(self, handler: Handler) -> OnDrag<Self, Handler>
+    where
+        Handler: Fn(DragValue),
+    {
+        OnDrag::new(self, handler)
+    }
+
+    /// Invokes the `action` whenever the value derived from the view's frame by `transform`
+    /// changes.
+    ///
+    /// Frames are fed by [`OnGeometryChange::measure`].
+    fn on_geometry_change<Derive, Action, Value>(
+        self,
+        transform: Derive,
+        action: Action,
+    ) -> OnGeometryChange<Self, Derive, Action, Value>
+    where
+        Derive: Fn(Rect) -> Value,
+        Action: Fn(Value),
+        Value: PartialEq + Clone,
+    {
+        OnGeometryChange::new(self, transform, action)
+    }
+
+    /// Runs the `action` whenever `key` is pressed, the result telling whether it was handled.
+    ///
+    /// Key presses are routed to the handlers by [`dispatch_key_press`].
+    fn on_key_press<Action>(self, key: KeyEquivalent, action: Action) -> OnKeyPress<Self, Action>
+    where
+        Action: Fn() -> KeyPressResult,
+    {
+        OnKeyPress::new(self, key, action)
+    }
+
+    /// Renders the view with an opacity of `value`, clamped to `[0, 1]`.
+    fn opacity(self, value: f64) -> OpacityEffect<Self> {
+        OpacityEffect::new(self, value)
+    }
+
+    /// Reports the `value` of `Key` to the view's ancestors.
+    fn preference<Key>(self, value: Key::Value) -> WithPreference<Self, Key>
+    where
+        Key: PreferenceKey,
+    {
+        WithPreference::new(self, value)
+    }
+
+    /// Registers the asynchronous `action` refreshing the view's content.
+    ///
+    /// The action is run by [`Refreshable::trigger_refresh`].
+    #[cfg(feature = "async")]
+    fn refreshable<Action, Fut>(self, action: Action) -> Refreshable<Self, Action>
+    where
+        Action: Fn() -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        Refreshable::new(self, action)
+    }
+
+    /// Pads the view by the safe area insets of its environment plus `extra` insets.
+    ///
+    /// The combined insets are resolved by [`SafeAreaPadding::resolve_insets`].
+    fn safe_area_padding(self, extra: EdgeInsets) -> SafeAreaPadding<Self> {
+        SafeAreaPadding::new(self, extra)
+    }
+
+    /// Registers the view as a scroll target identified by `id`.
+    ///
+    /// The frames of the anchors are looked up through a [`ScrollAnchorRegistry`].
+    fn scroll_anchor(self, id: impl Into<String>) -> ScrollAnchor<Self> {
+        ScrollAnchor::new(self, id.into())
+    }
+
+    /// Binds a scroll container to the `position` of its targeted item.
+    fn scroll_position<Id>(self, position: ScrollPosition<Id>) -> WithScrollPosition<Self, Id> {
+        WithScrollPosition::new(self, position)
+    }
+
+    /// Fires the sensory `effect` whenever the `trigger` value changes.
+    ///
+    /// Changes are detected by [`SensoryFeedback::reevaluate`].
+    fn sensory_feedback<Trigger>(
+        self,
+        trigger: Trigger,
+        effect: Effect,
+    ) -> SensoryFeedback<Self, Trigger>
+    where
+        Trigger: PartialEq,
+    {
+        SensoryFeedback::new(self, trigger, effect)
+    }
+
+    /// Tags the view with the metadata of its tab in a [`TabView`](crate::view::TabView).
+    fn tab_item(self, label: impl Into<String>, system_image: Option<String>) -> TabItem<Self> {
+        TabItem::new(self, TabLabel::new(label, system_image))
+    }
+
+    /// Invokes the `action` with the offset of the view within its scroll container on every
+    /// layout pass.
+    ///
+    /// Frames are fed by [`TrackScrollOffset::measure`].
+    fn track_scroll_offset<Action>(self, action: Action) -> TrackScrollOffset<Self, Action>
+    where
+        Action: Fn(f64),
+    {
+        TrackScrollOffset::new(self, action)
+    }
+
+    /// Adjusts the [`Transaction`] seen by the view's content.
+    fn transaction<Modify>(self, modify: Modify) -> WithTransaction<Self, Modify>
+    where
+        Modify: Fn(&mut Transaction),
+    {
+        WithTransaction::new(self, modify)
+    }
+
+    /// Sets where the text of the view is truncated when it does not fit its lines.
+    fn truncation_mode(self, mode: TruncationMode) -> Truncation<Self> {
+        Truncation::new(self, mode)
+    }
+
+    /// Sets the z-index of the view, ordering its rendering among overlapping siblings.
+    fn z_index(self, value: f64) -> ZIndex<Self> {
+        ZIndex::new(self, value)
+    }
+}
+
+impl<ViewType> ViewExt for ViewType where ViewType: View {}
@@ -1,8 +1,76 @@
 //! [`view`](crate::view) module default implementations.
+//!
+//! Tuples of views are views too, composing their elements in order into a single view whose body
+//! is the tuple of the bodies of its elements, for tuples of one to ten elements.
+//! ```
+//! # use viewbuilder_core as viewbuilder;
+//! use viewbuilder::{
+//!     prelude::*,
+//!     view::{
+//!         EmptyView,
+//!         Text,
+//!     },
+//!     View,
+//! };
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Greeting;
+//!
+//! impl View for Greeting {
+//!     type Body = Text;
+//!
+//!     fn body(&self) -> Self::Body {
+//!         Text::new("Hello")
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let screen = (Greeting, Text::new("World"), ().opacity(0.5));
+//!     let (greeting, text, faded) = screen.body();
+//!     assert_eq!(greeting, Text::new("Hello"));
+//!     assert_eq!(text, EmptyView);
+//!     assert_eq!(faded.value(), 0.5);
+//! }
+//! ```
 
 use super::*;
 
 impl View for Unit {
     type Body = Self;
     fn body(&self) -> Self::Body {}
+    fn collect_preferences(&self, _preferences: &mut PreferenceValues) {}
 }
+
+macro_rules! impl_view_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name),+> View for ($($name,)+)
+        where
+            $($name: View,)+
+        {
+            type Body = ($($name::Body,)+);
+
+            #[allow(non_snake_case)]
+            fn body(&self) -> Self::Body {
+                let ($($name,)+) = self;
+                ($($name.body(),)+)
+            }
+
+            #[allow(non_snake_case)]
+            fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+                let ($($name,)+) = self;
+                $($name.collect_preferences(preferences);)+
+            }
+        }
+    };
+}
+
+impl_view_for_tuple!(A);
+impl_view_for_tuple!(A, B);
+impl_view_for_tuple!(A, B, C);
+impl_view_for_tuple!(A, B, C, D);
+impl_view_for_tuple!(A, B, C, D, E);
+impl_view_for_tuple!(A, B, C, D, E, F);
+impl_view_for_tuple!(A, B, C, D, E, F, G);
+impl_view_for_tuple!(A, B, C, D, E, F, G, H);
+impl_view_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_view_for_tuple!(A, B, C, D, E, F, G, H, I, J);
@@ -1,8 +1,26 @@
 //! The core view abstractions.
 
+mod either;
+mod empty;
+pub mod environment;
+mod ext;
 mod impls;
+pub mod modifiers;
+pub mod preference;
+mod tab_view;
+mod text;
 
 use crate::prelude::*;
+use preference::PreferenceValues;
+
+pub use either::Either;
+pub use empty::EmptyView;
+pub use ext::ViewExt;
+pub use tab_view::{
+    TabItems,
+    TabView,
+};
+pub use text::Text;
 
 /// The core trait used to describe the building blocks of a user interface.
 pub trait View
@@ -14,4 +32,12 @@ where
 
     /// The content and behaviour of the view.
     fn body(&self) -> Self::Body;
+
+    /// Reduces the preferences set by the view and its descendants into `preferences`, in
+    /// traversal order.
+    ///
+    /// Views report the preferences of their body unless they override it.
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.body().collect_preferences(preferences);
+    }
 }
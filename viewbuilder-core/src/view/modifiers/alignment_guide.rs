@@ -0,0 +1,95 @@
+//! [`AlignmentGuide`](crate::view::modifiers::AlignmentGuide) definition.
+
+use super::*;
+use crate::layout::{
+    Guide,
+    Size,
+};
+use std::sync::Arc;
+
+/// A view overriding one of its alignment guides.
+///
+/// The guide is computed from the size of the view, as an offset from its leading edge for
+/// horizontal guides or its top edge for vertical ones. Stack layouts line the overridden guide up
+/// with the guides of the other subviews through their `place_subviews_with_guides` method.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     prelude::*,
+/// };
+///
+/// fn main() {
+///     let indented = ().alignment_guide(HorizontalAlignment::Leading, |size| size.width() / 2.0);
+///     let subviews = [Size::new(10.0, 4.0), Size::new(20.0, 6.0)];
+///     let guides = [
+///         None,
+///         indented.value_for(HorizontalAlignment::Leading.into(), subviews[1]),
+///     ];
+///     assert_eq!(guides[1], Some(10.0));
+///
+///     let layout = VStackLayout::new(0.0).with_alignment(HorizontalAlignment::Leading);
+///     let bounds = Rect::new(Position::zero(), Size::new(20.0, 10.0));
+///     let origins = layout.place_subviews_with_guides(bounds, &subviews, &guides);
+///     assert_eq!(
+///         origins,
+///         vec![Position::new(10.0, 0.0), Position::new(0.0, 4.0)]
+///     );
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AlignmentGuide<Content, Compute> {
+    content: Content,
+    guide: Guide,
+    compute: Arc<Compute>,
+}
+
+impl<Content, Compute> AlignmentGuide<Content, Compute>
+where
+    Compute: Fn(Size) -> f64,
+{
+    pub(crate) fn new(content: Content, guide: Guide, compute: Compute) -> Self {
+        Self {
+            content,
+            guide,
+            compute: Arc::new(compute),
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The overridden guide.
+    pub fn guide(&self) -> Guide {
+        self.guide
+    }
+
+    /// The overridden value of `guide` for a view of the given `size`.
+    ///
+    /// Returns `None` when `guide` is not the overridden guide.
+    pub fn value_for(&self, guide: Guide, size: Size) -> Option<f64> {
+        (guide == self.guide).then(|| (self.compute)(size))
+    }
+}
+
+impl<Content, Compute> View for AlignmentGuide<Content, Compute>
+where
+    Content: View,
+    Compute: Fn(Size) -> f64 + SendSync,
+{
+    type Body = AlignmentGuide<Content::Body, Compute>;
+
+    fn body(&self) -> Self::Body {
+        AlignmentGuide {
+            content: self.content.body(),
+            guide: self.guide,
+            compute: Arc::clone(&self.compute),
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,160 @@
+//! [`AllowsHitTesting`](crate::view::modifiers::AllowsHitTesting) definition.
+
+use super::*;
+use crate::layout::{
+    Position,
+    Rect,
+};
+
+/// A view whose subtree is excluded from, or included in, pointer hit-testing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllowsHitTesting<Content> {
+    content: Content,
+    enabled: bool,
+}
+
+impl<Content> AllowsHitTesting<Content> {
+    pub(crate) fn new(content: Content, enabled: bool) -> Self {
+        Self { content, enabled }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Whether the subtree receives pointer events.
+    pub fn is_interactive(&self) -> bool {
+        self.enabled
+    }
+
+    /// The hit-testing region of the view when laid out in `frame`.
+    pub fn hit_region(&self, frame: Rect) -> HitRegion {
+        HitRegion::new(frame).allows_hit_testing(self.enabled)
+    }
+}
+
+impl<Content> View for AllowsHitTesting<Content>
+where
+    Content: View,
+{
+    type Body = AllowsHitTesting<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        AllowsHitTesting::new(self.content.body(), self.enabled)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
+
+/// The frame of a view considered during hit-testing, along with the regions of its children.
+///
+/// Regions form a tree mirroring the view hierarchy, so disabling hit-testing on a region excludes
+/// its whole subtree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitRegion {
+    frame: Rect,
+    enabled: bool,
+    children: Vec<HitRegion>,
+}
+
+impl HitRegion {
+    /// Creates an interactive region covering `frame`, without children.
+    pub fn new(frame: Rect) -> Self {
+        Self {
+            frame,
+            enabled: true,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets whether the region and its subtree receive pointer events.
+    pub fn allows_hit_testing(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+
+    /// Appends a `child` region, painted above the previously appended ones.
+    pub fn child(mut self, child: HitRegion) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// The frame of the region.
+    pub fn frame(&self) -> Rect {
+        self.frame
+    }
+
+    /// Whether the region and its subtree receive pointer events.
+    pub fn is_interactive(&self) -> bool {
+        self.enabled
+    }
+
+    /// The regions of the children, in painting order.
+    pub fn children(&self) -> &[HitRegion] {
+        &self.children
+    }
+
+    /// Appends the path to the topmost interactive region of the subtree containing `point`.
+    fn hit(&self, point: Position, path: &mut Vec<usize>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        for (index, child) in self.children.iter().enumerate().rev() {
+            path.push(index);
+            if child.hit(point, path) {
+                return true;
+            }
+            path.pop();
+        }
+        self.frame.contains(point)
+    }
+}
+
+/// Finds the topmost interactive region containing `point` in the tree starting at `root`.
+///
+/// Children are painted above their parent, in order from back to front, so the last matching
+/// child wins over its previous siblings and over its parent. Regions that do not allow
+/// hit-testing are skipped along with their whole subtree, letting the hit fall through to the
+/// regions below. Returns the path of child indices leading from the root to the hit region, empty
+/// when the root itself is hit.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     prelude::*,
+///     view::modifiers::{
+///         hit_test,
+///         HitRegion,
+///     },
+/// };
+///
+/// fn main() {
+///     let frame = Rect::new(Position::zero(), Size::new(100.0, 100.0));
+///     let button = Rect::new(Position::new(10.0, 10.0), Size::new(20.0, 20.0));
+///     let overlay = ().allows_hit_testing(false);
+///     assert!(!overlay.is_interactive());
+///
+///     let root = HitRegion::new(frame)
+///         .child(HitRegion::new(frame).child(HitRegion::new(button)))
+///         .child(overlay.hit_region(frame).child(HitRegion::new(frame)));
+///
+///     // The disabled overlay and its enabled child let hits fall through.
+///     assert_eq!(hit_test(&root, Position::new(15.0, 15.0)), Some(vec![0, 0]));
+///     assert_eq!(hit_test(&root, Position::new(50.0, 50.0)), Some(vec![0]));
+///     assert_eq!(hit_test(&root, Position::new(150.0, 50.0)), None);
+///
+///     let disabled = HitRegion::new(frame).allows_hit_testing(false);
+///     assert_eq!(hit_test(&disabled, Position::new(50.0, 50.0)), None);
+///     assert_eq!(
+///         hit_test(&HitRegion::new(frame), Position::new(50.0, 50.0)),
+///         Some(vec![])
+///     );
+/// }
+/// ```
+pub fn hit_test(root: &HitRegion, point: Position) -> Option<Vec<usize>> {
+    let mut path = Vec::new();
+    root.hit(point, &mut path).then_some(path)
+}
@@ -0,0 +1,57 @@
+//! [`CompositingGroup`](crate::view::modifiers::CompositingGroup) definition.
+
+use super::*;
+
+/// A view whose subtree is composited into a single layer before group-level effects apply.
+///
+/// Effects like [`opacity`](crate::view::ViewExt::opacity) then apply to the flattened layer, so
+/// overlapping children do not show through each other.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     View,
+/// };
+///
+/// fn main() {
+///     let group = ().compositing_group();
+///     assert!(group.is_composited());
+///     assert_eq!(group.content(), &());
+///     assert!(group.body().is_composited());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompositingGroup<Content> {
+    content: Content,
+}
+
+impl<Content> CompositingGroup<Content> {
+    pub(crate) fn new(content: Content) -> Self {
+        Self { content }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Whether a backend should composite the subtree as a single layer.
+    pub fn is_composited(&self) -> bool {
+        true
+    }
+}
+
+impl<Content> View for CompositingGroup<Content>
+where
+    Content: View,
+{
+    type Body = CompositingGroup<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        CompositingGroup::new(self.content.body())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
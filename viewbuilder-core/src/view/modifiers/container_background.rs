@@ -0,0 +1,79 @@
+//! [`ContainerBackground`](crate::view::modifiers::ContainerBackground) definition.
+
+use super::*;
+
+/// A view providing the background of an enclosing container.
+///
+/// The background fills the bounds of the container identified by its coordinate space name
+/// rather than those of the view declaring it.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     View,
+/// };
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Fill;
+///
+/// impl View for Fill {
+///     type Body = Unit;
+///
+///     fn body(&self) -> Self::Body {}
+/// }
+///
+/// fn main() {
+///     let card = ().container_background(Fill, "card");
+///     assert_eq!(card.background(), &Fill);
+///     assert_eq!(card.container(), "card");
+///     assert_eq!(card.body().container(), "card");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerBackground<Content, Background> {
+    content: Content,
+    background: Background,
+    container: &'static str,
+}
+
+impl<Content, Background> ContainerBackground<Content, Background> {
+    pub(crate) fn new(content: Content, background: Background, container: &'static str) -> Self {
+        Self {
+            content,
+            background,
+            container,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The view filling the container.
+    pub fn background(&self) -> &Background {
+        &self.background
+    }
+
+    /// The coordinate space name of the container the background fills.
+    pub fn container(&self) -> &'static str {
+        self.container
+    }
+}
+
+impl<Content, Background> View for ContainerBackground<Content, Background>
+where
+    Content: View,
+    Background: View + Clone,
+{
+    type Body = ContainerBackground<Content::Body, Background>;
+
+    fn body(&self) -> Self::Body {
+        ContainerBackground::new(self.content.body(), self.background.clone(), self.container)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+        self.background.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,63 @@
+//! [`DrawingGroup`](crate::view::modifiers::DrawingGroup) definition.
+
+use super::*;
+
+/// A view whose subtree should be flattened into a single rasterized unit.
+///
+/// This is only a hint for rendering backends, the content is otherwise left untouched.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     View,
+/// };
+///
+/// struct Badge;
+///
+/// impl View for Badge {
+///     type Body = Unit;
+///
+///     fn body(&self) -> Self::Body {}
+/// }
+///
+/// fn main() {
+///     let badge = Badge.drawing_group();
+///     assert!(badge.is_flattened());
+///     assert!(badge.body().is_flattened());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawingGroup<Content> {
+    content: Content,
+}
+
+impl<Content> DrawingGroup<Content> {
+    pub(crate) fn new(content: Content) -> Self {
+        Self { content }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Whether a backend should rasterize the subtree as a single unit.
+    pub fn is_flattened(&self) -> bool {
+        true
+    }
+}
+
+impl<Content> View for DrawingGroup<Content>
+where
+    Content: View,
+{
+    type Body = DrawingGroup<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        DrawingGroup::new(self.content.body())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,77 @@
+//! [`Equatable`](crate::view::modifiers::Equatable) definition.
+
+use super::*;
+
+/// A view whose re-evaluation can be skipped when it compares equal to its previous value.
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     View,
+/// };
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Counter(u32);
+///
+/// impl View for Counter {
+///     type Body = Unit;
+///
+///     fn body(&self) -> Self::Body {}
+/// }
+///
+/// fn main() {
+///     let mut counter = Counter(0).equatable();
+///     assert!(!counter.should_update(Counter(0)));
+///     assert!(counter.should_update(Counter(1)));
+///     assert_eq!(counter.content(), &Counter(1));
+///     assert!(!counter.should_update(Counter(1)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Equatable<Content> {
+    content: Content,
+}
+
+impl<Content> Equatable<Content> {
+    pub(crate) fn new(content: Content) -> Self {
+        Self { content }
+    }
+
+    /// The wrapped view, as last evaluated.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+}
+
+impl<Content> Equatable<Content>
+where
+    Content: PartialEq,
+{
+    /// Re-evaluates the view with its `new` value.
+    ///
+    /// Returns whether the value differs from the previous one, which it then replaces.
+    pub fn should_update(&mut self, new: Content) -> bool {
+        if self.content == new {
+            return false;
+        }
+
+        self.content = new;
+        true
+    }
+}
+
+impl<Content> View for Equatable<Content>
+where
+    Content: View,
+{
+    type Body = Equatable<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        Equatable::new(self.content.body())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,257 @@
+//! [`Focusable`](crate::view::modifiers::Focusable) and
+//! [`Focused`](crate::view::modifiers::Focused) definitions.
+
+use super::*;
+use std::sync::{
+    Arc,
+    PoisonError,
+    RwLock,
+};
+
+/// The identifier of a view taking part in focus tracking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FocusId(u64);
+
+impl FocusId {
+    /// Creates an identifier from its raw `value`.
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// The raw value of the identifier.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A view that can or cannot receive the keyboard focus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Focusable<Content> {
+    content: Content,
+    is_focusable: bool,
+}
+
+impl<Content> Focusable<Content> {
+    pub(crate) fn new(content: Content, is_focusable: bool) -> Self {
+        Self {
+            content,
+            is_focusable,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Whether the view can receive the focus.
+    pub fn is_focusable(&self) -> bool {
+        self.is_focusable
+    }
+}
+
+impl<Content> View for Focusable<Content>
+where
+    Content: View,
+{
+    type Body = Focusable<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        Focusable::new(self.content.body(), self.is_focusable)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
+
+/// A two-way binding to the identifier of the focused view.
+///
+/// Clones share the same state, so every [`Focused`] view and the code driving the focus can hold
+/// the binding.
+#[derive(Debug, Default)]
+pub struct FocusState {
+    focused: Arc<RwLock<Option<FocusId>>>,
+}
+
+impl FocusState {
+    /// Creates a binding without a focused view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The identifier of the focused view.
+    pub fn focused(&self) -> Option<FocusId> {
+        *self.focused.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replaces the focused view.
+    pub fn set_focused(&self, focused: Option<FocusId>) {
+        *self.focused.write().unwrap_or_else(PoisonError::into_inner) = focused;
+    }
+
+    /// Focuses the view identified by `id`.
+    pub fn focus(&self, id: FocusId) {
+        self.set_focused(Some(id));
+    }
+
+    /// Removes the focus from every view.
+    pub fn clear(&self) {
+        self.set_focused(None);
+    }
+}
+
+impl Clone for FocusState {
+    fn clone(&self) -> Self {
+        Self {
+            focused: Arc::clone(&self.focused),
+        }
+    }
+}
+
+/// A view bound to a [`FocusState`] under an identifier.
+#[derive(Debug, Clone)]
+pub struct Focused<Content> {
+    content: Content,
+    id: FocusId,
+    state: FocusState,
+}
+
+impl<Content> Focused<Content> {
+    pub(crate) fn new(content: Content, id: FocusId, state: FocusState) -> Self {
+        Self { content, id, state }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The identifier of the view.
+    pub fn id(&self) -> FocusId {
+        self.id
+    }
+
+    /// The bound focus state.
+    pub fn state(&self) -> &FocusState {
+        &self.state
+    }
+
+    /// Whether the view holds the focus.
+    pub fn is_focused(&self) -> bool {
+        self.state.focused() == Some(self.id)
+    }
+}
+
+impl<Content> View for Focused<Content>
+where
+    Content: View,
+{
+    type Body = Focused<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        Focused::new(self.content.body(), self.id, self.state.clone())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
+
+/// Records the focusable views in traversal order so the focus can move between them.
+///
+/// Only views made [`Focusable`] then bound with [`Focused`] take part in the traversal, the ones
+/// that are not focusable are skipped when registered. Moving past either end wraps around.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::modifiers::{
+///         FocusId,
+///         FocusOrder,
+///         FocusState,
+///     },
+/// };
+///
+/// fn main() {
+///     let state = FocusState::new();
+///     let (name, email) = (FocusId::new(1), FocusId::new(2));
+///     let name_field = ().focusable(true).focused(name, state.clone());
+///     let label = ().focusable(false).focused(FocusId::new(3), state.clone());
+///     let email_field = ().focusable(true).focused(email, state.clone());
+///
+///     let mut order = FocusOrder::new();
+///     order.register(&name_field);
+///     order.register(&label);
+///     order.register(&email_field);
+///     assert_eq!(order.ids(), &[name, email]);
+///
+///     assert_eq!(order.focus_next(&state), Some(name));
+///     assert!(name_field.is_focused());
+///     assert_eq!(order.focus_next(&state), Some(email));
+///     assert!(!name_field.is_focused() && email_field.is_focused());
+///     assert_eq!(order.focus_next(&state), Some(name));
+///     assert_eq!(order.focus_previous(&state), Some(email));
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FocusOrder {
+    ids: Vec<FocusId>,
+}
+
+impl FocusOrder {
+    /// Creates an empty traversal order.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a view to the traversal order, unless it is not focusable.
+    pub fn register<Content>(&mut self, view: &Focused<Focusable<Content>>) {
+        if view.content().is_focusable() {
+            self.ids.push(view.id());
+        }
+    }
+
+    /// The identifiers of the focusable views, in traversal order.
+    pub fn ids(&self) -> &[FocusId] {
+        &self.ids
+    }
+
+    /// Moves the focus of `state` to the view following the focused one.
+    ///
+    /// The first view is focused when no registered view holds the focus. Returns the newly
+    /// focused view, `None` when no view was registered.
+    pub fn focus_next(&self, state: &FocusState) -> Option<FocusId> {
+        let next = match self.position(state) {
+            Some(index) => self.ids.get((index + 1) % self.ids.len()),
+            None => self.ids.first(),
+        };
+        self.focus(state, next.copied())
+    }
+
+    /// Moves the focus of `state` to the view preceding the focused one.
+    ///
+    /// The last view is focused when no registered view holds the focus. Returns the newly
+    /// focused view, `None` when no view was registered.
+    pub fn focus_previous(&self, state: &FocusState) -> Option<FocusId> {
+        let previous = match self.position(state) {
+            Some(index) => self.ids.get((index + self.ids.len() - 1) % self.ids.len()),
+            None => self.ids.last(),
+        };
+        self.focus(state, previous.copied())
+    }
+
+    /// The index of the focused view in the traversal order.
+    fn position(&self, state: &FocusState) -> Option<usize> {
+        let focused = state.focused()?;
+        self.ids.iter().position(|&id| id == focused)
+    }
+
+    /// Focuses `id` when there is one, leaving `state` unchanged otherwise.
+    fn focus(&self, state: &FocusState, id: Option<FocusId>) -> Option<FocusId> {
+        if let Some(id) = id {
+            state.focus(id);
+        }
+        id
+    }
+}
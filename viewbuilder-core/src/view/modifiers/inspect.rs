@@ -0,0 +1,76 @@
+//! [`Inspect`](crate::view::modifiers::Inspect) definition.
+
+use super::*;
+use std::sync::Arc;
+
+/// A view probing its content whenever its body is evaluated.
+///
+/// The probe receives the content before its body is evaluated, the body itself being returned
+/// unchanged so the modifier leaves no trace in the view hierarchy.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::{
+///     atomic::{
+///         AtomicUsize,
+///         Ordering,
+///     },
+///     Arc,
+/// };
+/// use viewbuilder::{
+///     prelude::*,
+///     View,
+/// };
+///
+/// fn main() {
+///     let inspections = Arc::new(AtomicUsize::new(0));
+///     let counter = Arc::clone(&inspections);
+///     let view = ().drawing_group().inspect(move |group| {
+///         assert!(group.is_flattened());
+///         counter.fetch_add(1, Ordering::SeqCst);
+///     });
+///     assert_eq!(inspections.load(Ordering::SeqCst), 0);
+///
+///     let body = view.body();
+///     assert_eq!(inspections.load(Ordering::SeqCst), 1);
+///     assert_eq!(body, ().drawing_group().body());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Inspect<Content, Probe> {
+    content: Content,
+    probe: Arc<Probe>,
+}
+
+impl<Content, Probe> Inspect<Content, Probe>
+where
+    Probe: Fn(&Content),
+{
+    pub(crate) fn new(content: Content, probe: Probe) -> Self {
+        Self {
+            content,
+            probe: Arc::new(probe),
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+}
+
+impl<Content, Probe> View for Inspect<Content, Probe>
+where
+    Content: View,
+    Probe: Fn(&Content) + SendSync,
+{
+    type Body = Content::Body;
+
+    fn body(&self) -> Self::Body {
+        (self.probe)(&self.content);
+        self.content.body()
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
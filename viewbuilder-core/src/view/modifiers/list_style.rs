@@ -0,0 +1,54 @@
+//! [`ListStyle`](crate::view::modifiers::ListStyle) definition.
+
+use crate::view::environment::{
+    EnvironmentKey,
+    EnvironmentValues,
+};
+
+/// The appearance of the lists of a view hierarchy.
+///
+/// Set with the [`list_style`](crate::view::ViewExt::list_style) modifier and read by list
+/// containers with [`ListStyle::current`].
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::{
+///         environment::*,
+///         modifiers::ListStyle,
+///     },
+/// };
+///
+/// fn main() {
+///     let root = EnvironmentValues::new();
+///     assert_eq!(ListStyle::current(&root), ListStyle::Plain);
+///
+///     let list = ().transaction(|_| {}).list_style(ListStyle::Grouped);
+///     let list_environment = list.resolve_environment(&root);
+///     let row_environment = list.content().resolve_environment(&list_environment);
+///     assert_eq!(ListStyle::current(&row_environment), ListStyle::Grouped);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ListStyle {
+    /// Rows laid out edge to edge without decoration.
+    #[default]
+    Plain,
+    /// Rows laid out in visually distinct sections.
+    Grouped,
+    /// Rows laid out in sections inset from the edges of the container.
+    Inset,
+}
+
+impl ListStyle {
+    /// The list style of an `environment`.
+    pub fn current(environment: &EnvironmentValues) -> Self {
+        environment.get::<Self>()
+    }
+}
+
+impl EnvironmentKey for ListStyle {
+    type Value = Self;
+
+    const DEFAULT: Self::Value = Self::Plain;
+}
@@ -0,0 +1,124 @@
+//! [`MatchedGeometry`](crate::view::modifiers::MatchedGeometry) definition.
+
+use super::*;
+use crate::layout::Rect;
+use std::collections::HashMap;
+
+/// A view tagged for a shared element transition.
+///
+/// Views sharing the same identifier within the same namespace describe the source and target of a
+/// transition. Their frames can be paired through a [`MatchedGeometryRegistry`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchedGeometry<Content> {
+    content: Content,
+    id: String,
+    namespace: &'static str,
+}
+
+impl<Content> MatchedGeometry<Content> {
+    pub(crate) fn new(content: Content, id: String, namespace: &'static str) -> Self {
+        Self {
+            content,
+            id,
+            namespace,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The identifier shared between the source and target views.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The namespace the identifier belongs to.
+    pub fn namespace(&self) -> &'static str {
+        self.namespace
+    }
+}
+
+impl<Content> View for MatchedGeometry<Content>
+where
+    Content: View,
+{
+    type Body = MatchedGeometry<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        MatchedGeometry::new(self.content.body(), self.id.clone(), self.namespace)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
+
+/// Records the frames of [`MatchedGeometry`] views so sources and targets can be paired.
+///
+/// The first frame registered for an identifier is the source and the last one is the target.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::{
+///         Position,
+///         Rect,
+///         Size,
+///     },
+///     prelude::*,
+///     view::modifiers::MatchedGeometryRegistry,
+/// };
+///
+/// fn main() {
+///     let thumbnail = ().matched_geometry("photo", "gallery");
+///     let detail = ().matched_geometry("photo", "gallery");
+///     let source = Rect::new(Position::zero(), Size::new(10.0, 10.0));
+///     let target = Rect::new(Position::new(50.0, 50.0), Size::new(100.0, 100.0));
+///
+///     let mut registry = MatchedGeometryRegistry::new();
+///     registry.register(&thumbnail, source);
+///     assert_eq!(registry.pair("photo", "gallery"), None);
+///
+///     registry.register(&detail, target);
+///     assert_eq!(registry.pair("photo", "gallery"), Some((source, target)));
+///     assert_eq!(registry.pair("photo", "profile"), None);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MatchedGeometryRegistry {
+    frames: HashMap<&'static str, HashMap<String, Vec<Rect>>>,
+}
+
+impl MatchedGeometryRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the `frame` of a tagged view.
+    pub fn register<Content>(&mut self, view: &MatchedGeometry<Content>, frame: Rect) {
+        self.frames
+            .entry(view.namespace())
+            .or_default()
+            .entry(view.id().to_owned())
+            .or_default()
+            .push(frame);
+    }
+
+    /// The frames registered for an identifier, in registration order.
+    pub fn frames(&self, id: &str, namespace: &'static str) -> &[Rect] {
+        self.frames
+            .get(namespace)
+            .and_then(|ids| ids.get(id))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The source and target frames for an identifier, if both were registered.
+    pub fn pair(&self, id: &str, namespace: &'static str) -> Option<(Rect, Rect)> {
+        match self.frames(id, namespace) {
+            [source, .., target] => Some((*source, *target)),
+            _ => None,
+        }
+    }
+}
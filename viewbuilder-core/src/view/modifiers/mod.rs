@@ -0,0 +1,108 @@
+//! View modifiers.
+//!
+//! The wrappers in this module are produced by the [`ViewExt`](crate::view::ViewExt) methods.
+
+mod alignment_guide;
+mod allows_hit_testing;
+mod compositing_group;
+mod container_background;
+mod drawing_group;
+mod equatable;
+mod focus;
+mod inspect;
+mod list_style;
+mod matched_geometry;
+mod navigation_title;
+mod on_drag;
+mod on_geometry_change;
+mod on_key_press;
+mod opacity;
+#[cfg(feature = "async")]
+mod refreshable;
+mod safe_area_padding;
+mod scroll_anchor;
+mod scroll_position;
+mod sensory_feedback;
+mod tab_item;
+mod text_layout;
+mod track_scroll_offset;
+mod transaction;
+mod with_environment;
+mod with_preference;
+mod z_index;
+
+use super::*;
+
+pub use alignment_guide::AlignmentGuide;
+pub use allows_hit_testing::{
+    hit_test,
+    AllowsHitTesting,
+    HitRegion,
+};
+pub use compositing_group::CompositingGroup;
+pub use container_background::ContainerBackground;
+pub use drawing_group::DrawingGroup;
+pub use equatable::Equatable;
+pub use focus::{
+    FocusId,
+    FocusOrder,
+    FocusState,
+    Focusable,
+    Focused,
+};
+pub use inspect::Inspect;
+pub use list_style::ListStyle;
+pub use matched_geometry::{
+    MatchedGeometry,
+    MatchedGeometryRegistry,
+};
+pub use navigation_title::NavigationTitleKey;
+pub use on_drag::{
+    DragValue,
+    OnDrag,
+};
+pub use on_geometry_change::OnGeometryChange;
+pub use on_key_press::{
+    dispatch_key_press,
+    KeyEquivalent,
+    KeyPressHandler,
+    KeyPressResult,
+    OnKeyPress,
+};
+pub use opacity::OpacityEffect;
+#[cfg(feature = "async")]
+pub use refreshable::Refreshable;
+pub use safe_area_padding::{
+    SafeAreaInsets,
+    SafeAreaPadding,
+};
+pub use scroll_anchor::{
+    ScrollAnchor,
+    ScrollAnchorRegistry,
+};
+pub use scroll_position::{
+    ScrollPosition,
+    WithScrollPosition,
+};
+pub use sensory_feedback::{
+    Effect,
+    SensoryFeedback,
+};
+pub use tab_item::{
+    TabItem,
+    TabLabel,
+};
+pub use text_layout::{
+    LineLimit,
+    Truncation,
+    TruncationMode,
+};
+pub use track_scroll_offset::TrackScrollOffset;
+pub use transaction::{
+    Animation,
+    Transaction,
+    WithTransaction,
+};
+pub use with_environment::WithEnvironment;
+pub use with_preference::WithPreference;
+pub use z_index::ZIndex;
@@ -0,0 +1,66 @@
+//! [`NavigationTitleKey`](crate::view::modifiers::NavigationTitleKey) definition.
+
+use crate::view::preference::PreferenceKey;
+
+/// The preference key of the title shown by the enclosing navigation container.
+///
+/// Set with the [`navigation_title`](crate::view::ViewExt::navigation_title) modifier. When
+/// several views set a title, the last non-empty one wins. A container reads the title set
+/// anywhere within its content by collecting its preferences.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::{
+///         modifiers::{
+///             NavigationTitleKey,
+///             OpacityEffect,
+///             WithPreference,
+///         },
+///         preference::*,
+///         Either,
+///         EmptyView,
+///         Text,
+///     },
+///     View,
+/// };
+///
+/// #[derive(Debug)]
+/// struct Inbox;
+///
+/// impl View for Inbox {
+///     type Body = OpacityEffect<WithPreference<Text, NavigationTitleKey>>;
+///
+///     fn body(&self) -> Self::Body {
+///         Text::new("No new messages")
+///             .navigation_title("Inbox")
+///             .opacity(0.5)
+///     }
+/// }
+///
+/// fn main() {
+///     let screen = (
+///         Text::new("Mailboxes").navigation_title("Mailboxes"),
+///         Either::<EmptyView, _>::right((Text::new("Unread"), Inbox).z_index(1.0)),
+///         ().navigation_title(""),
+///     );
+///
+///     let mut preferences = PreferenceValues::new();
+///     screen.collect_preferences(&mut preferences);
+///     assert_eq!(preferences.get::<NavigationTitleKey>(), "Inbox");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct NavigationTitleKey;
+
+impl PreferenceKey for NavigationTitleKey {
+    type Value = String;
+
+    const DEFAULT: Self::Value = String::new();
+
+    fn reduce(value: &mut Self::Value, next: Self::Value) {
+        if !next.is_empty() {
+            *value = next;
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! [`OnDrag`](crate::view::modifiers::OnDrag) definitions.
+
+use super::*;
+use crate::layout::{
+    Position,
+    Vector,
+};
+use std::sync::Arc;
+
+/// The state of an ongoing drag gesture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragValue {
+    start: Position,
+    current: Position,
+}
+
+impl DragValue {
+    /// Creates the value of a drag that went from `start` to `current`.
+    pub fn new(start: Position, current: Position) -> Self {
+        Self { start, current }
+    }
+
+    /// Where the drag started.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Where the drag currently is.
+    pub fn current(&self) -> Position {
+        self.current
+    }
+
+    /// The displacement from the start of the drag to its current position.
+    pub fn translation(&self) -> Vector {
+        Vector::new(
+            self.current.x() - self.start.x(),
+            self.current.y() - self.start.y(),
+        )
+    }
+}
+
+/// A view handling drag gestures.
+///
+/// Pointer samples are fed through [`begin_drag`](OnDrag::begin_drag),
+/// [`drag_to`](OnDrag::drag_to) and [`end_drag`](OnDrag::end_drag), the handler receiving a
+/// [`DragValue`] for each of them while a drag is ongoing.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::{
+///     Arc,
+///     Mutex,
+/// };
+/// use viewbuilder::{
+///     layout::*,
+///     prelude::*,
+/// };
+///
+/// fn main() {
+///     let translations = Arc::new(Mutex::new(Vec::new()));
+///     let recorded = Arc::clone(&translations);
+///     let mut view = ().on_drag(move |value| recorded.lock().unwrap().push(value.translation()));
+///
+///     view.begin_drag(Position::new(10.0, 10.0));
+///     view.drag_to(Position::new(15.0, 22.0));
+///     view.end_drag();
+///     view.drag_to(Position::new(50.0, 50.0));
+///
+///     assert_eq!(
+///         *translations.lock().unwrap(),
+///         vec![Vector::zero(), Vector::new(5.0, 12.0)]
+///     );
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OnDrag<Content, Handler> {
+    content: Content,
+    handler: Arc<Handler>,
+    start: Option<Position>,
+}
+
+impl<Content, Handler> OnDrag<Content, Handler>
+where
+    Handler: Fn(DragValue),
+{
+    pub(crate) fn new(content: Content, handler: Handler) -> Self {
+        Self {
+            content,
+            handler: Arc::new(handler),
+            start: None,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Whether a drag is ongoing.
+    pub fn is_dragging(&self) -> bool {
+        self.start.is_some()
+    }
+
+    /// Starts a drag at `position`.
+    pub fn begin_drag(&mut self, position: Position) {
+        self.start = Some(position);
+        (self.handler)(DragValue::new(position, position));
+    }
+
+    /// Moves the ongoing drag to `position`, ignored when no drag is ongoing.
+    pub fn drag_to(&mut self, position: Position) {
+        if let Some(start) = self.start {
+            (self.handler)(DragValue::new(start, position));
+        }
+    }
+
+    /// Ends the ongoing drag.
+    pub fn end_drag(&mut self) {
+        self.start = None;
+    }
+}
+
+impl<Content, Handler> View for OnDrag<Content, Handler>
+where
+    Content: View,
+    Handler: Fn(DragValue) + SendSync,
+{
+    type Body = OnDrag<Content::Body, Handler>;
+
+    fn body(&self) -> Self::Body {
+        OnDrag {
+            content: self.content.body(),
+            handler: Arc::clone(&self.handler),
+            start: self.start,
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
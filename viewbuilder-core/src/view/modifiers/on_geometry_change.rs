@@ -0,0 +1,102 @@
+//! [`OnGeometryChange`](crate::view::modifiers::OnGeometryChange) definition.
+
+use super::*;
+use crate::layout::Rect;
+use std::sync::Arc;
+
+/// A view reacting to changes of a value derived from its measured frame.
+///
+/// Each measurement pass feeds the frame of the view to [`OnGeometryChange::measure`], which
+/// derives a value from it and invokes the action when that value differs from the previous one.
+/// The first measurement always invokes the action.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::{
+///     Arc,
+///     Mutex,
+/// };
+/// use viewbuilder::{
+///     layout::*,
+///     prelude::*,
+/// };
+///
+/// fn main() {
+///     let widths = Arc::new(Mutex::new(Vec::new()));
+///     let recorded = Arc::clone(&widths);
+///     let mut view = ().on_geometry_change(
+///         |frame| frame.size().width(),
+///         move |width| recorded.lock().unwrap().push(width),
+///     );
+///
+///     view.measure(Rect::new(Position::zero(), Size::new(10.0, 5.0)));
+///     view.measure(Rect::new(Position::new(3.0, 3.0), Size::new(10.0, 8.0)));
+///     view.measure(Rect::new(Position::zero(), Size::new(20.0, 5.0)));
+///
+///     assert_eq!(*widths.lock().unwrap(), vec![10.0, 20.0]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OnGeometryChange<Content, Derive, Action, Value> {
+    content: Content,
+    derive: Arc<Derive>,
+    action: Arc<Action>,
+    value: Option<Value>,
+}
+
+impl<Content, Derive, Action, Value> OnGeometryChange<Content, Derive, Action, Value>
+where
+    Derive: Fn(Rect) -> Value,
+    Action: Fn(Value),
+    Value: PartialEq + Clone,
+{
+    pub(crate) fn new(content: Content, derive: Derive, action: Action) -> Self {
+        Self {
+            content,
+            derive: Arc::new(derive),
+            action: Arc::new(action),
+            value: None,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The value derived from the last measured frame.
+    pub fn value(&self) -> Option<&Value> {
+        self.value.as_ref()
+    }
+
+    /// Feeds the measured `frame` of the view, invoking the action if the derived value changed.
+    pub fn measure(&mut self, frame: Rect) {
+        let value = (self.derive)(frame);
+        if self.value.as_ref() != Some(&value) {
+            self.value = Some(value.clone());
+            (self.action)(value);
+        }
+    }
+}
+
+impl<Content, Derive, Action, Value> View for OnGeometryChange<Content, Derive, Action, Value>
+where
+    Content: View,
+    Derive: Fn(Rect) -> Value + SendSync,
+    Action: Fn(Value) + SendSync,
+    Value: PartialEq + Clone + SendSync,
+{
+    type Body = OnGeometryChange<Content::Body, Derive, Action, Value>;
+
+    fn body(&self) -> Self::Body {
+        OnGeometryChange {
+            content: self.content.body(),
+            derive: Arc::clone(&self.derive),
+            action: Arc::clone(&self.action),
+            value: self.value.clone(),
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
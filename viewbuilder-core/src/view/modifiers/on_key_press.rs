@@ -0,0 +1,269 @@
+//! [`OnKeyPress`](crate::view::modifiers::OnKeyPress) definition.
+
+use super::*;
+use std::sync::Arc;
+
+/// A key that can be bound to a handler, identified by the character it produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyEquivalent(char);
+
+impl KeyEquivalent {
+    /// The return key.
+    pub const RETURN: Self = Self('\r');
+    /// The escape key.
+    pub const ESCAPE: Self = Self('\u{1b}');
+    /// The tab key.
+    pub const TAB: Self = Self('\t');
+    /// The space bar.
+    pub const SPACE: Self = Self(' ');
+    /// The delete key, erasing backward.
+    pub const DELETE: Self = Self('\u{8}');
+
+    /// Creates the key producing `character`.
+    pub const fn new(character: char) -> Self {
+        Self(character)
+    }
+
+    /// The character produced by the key.
+    pub fn character(&self) -> char {
+        self.0
+    }
+}
+
+impl From<char> for KeyEquivalent {
+    fn from(character: char) -> Self {
+        Self::new(character)
+    }
+}
+
+/// Whether a key press was consumed by a handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyPressResult {
+    /// The key press was consumed and stops being routed.
+    Handled,
+    /// The key press was not consumed and keeps being routed.
+    Ignored,
+}
+
+/// Views reacting to key presses.
+pub trait KeyPressHandler
+where
+    Self: SendSync,
+{
+    /// Handles the press of `key`.
+    fn handle_key_press(&self, key: KeyEquivalent) -> KeyPressResult;
+}
+
+/// A view running an action whenever a key is pressed.
+#[derive(Debug)]
+pub struct OnKeyPress<Content, Action> {
+    content: Content,
+    key: KeyEquivalent,
+    action: Arc<Action>,
+}
+
+impl<Content, Action> OnKeyPress<Content, Action>
+where
+    Action: Fn() -> KeyPressResult,
+{
+    pub(crate) fn new(content: Content, key: KeyEquivalent, action: Action) -> Self {
+        Self {
+            content,
+            key,
+            action: Arc::new(action),
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The key the action is bound to.
+    pub fn key(&self) -> KeyEquivalent {
+        self.key
+    }
+}
+
+impl KeyPressHandler for () {
+    /// Ignores every key press, having no handler.
+    fn handle_key_press(&self, _key: KeyEquivalent) -> KeyPressResult {
+        KeyPressResult::Ignored
+    }
+}
+
+impl KeyPressHandler for EmptyView {
+    /// Ignores every key press, having no handler.
+    fn handle_key_press(&self, _key: KeyEquivalent) -> KeyPressResult {
+        KeyPressResult::Ignored
+    }
+}
+
+impl<Content, Action> KeyPressHandler for OnKeyPress<Content, Action>
+where
+    Content: KeyPressHandler,
+    Action: Fn() -> KeyPressResult + SendSync,
+{
+    /// Runs the action when `key` is the bound key, routing the key press to the content when it
+    /// is another key or the action ignored it.
+    fn handle_key_press(&self, key: KeyEquivalent) -> KeyPressResult {
+        if key == self.key && (self.action)() == KeyPressResult::Handled {
+            KeyPressResult::Handled
+        } else {
+            self.content.handle_key_press(key)
+        }
+    }
+}
+
+impl<Content> KeyPressHandler for Focusable<Content>
+where
+    Content: KeyPressHandler,
+{
+    /// Routes key presses to the content only while the view is focusable.
+    fn handle_key_press(&self, key: KeyEquivalent) -> KeyPressResult {
+        if self.is_focusable() {
+            self.content().handle_key_press(key)
+        } else {
+            KeyPressResult::Ignored
+        }
+    }
+}
+
+impl<Content> KeyPressHandler for Focused<Content>
+where
+    Content: KeyPressHandler,
+{
+    /// Routes key presses to the content only while the view holds the focus.
+    fn handle_key_press(&self, key: KeyEquivalent) -> KeyPressResult {
+        if self.is_focused() {
+            self.content().handle_key_press(key)
+        } else {
+            KeyPressResult::Ignored
+        }
+    }
+}
+
+impl<Content, Action> View for OnKeyPress<Content, Action>
+where
+    Content: View,
+    Action: Fn() -> KeyPressResult + SendSync,
+{
+    type Body = OnKeyPress<Content::Body, Action>;
+
+    fn body(&self) -> Self::Body {
+        OnKeyPress {
+            content: self.content.body(),
+            key: self.key,
+            action: Arc::clone(&self.action),
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
+
+/// Routes the press of `key` through `handlers` until one of them handles it.
+///
+/// The `handlers` are expected in routing order, usually from the focused view outwards. Returns
+/// [`KeyPressResult::Ignored`] when no handler consumed the key press.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::atomic::{
+///     AtomicUsize,
+///     Ordering,
+/// };
+/// use viewbuilder::{
+///     prelude::*,
+///     view::modifiers::*,
+/// };
+///
+/// static SUBMITTED: AtomicUsize = AtomicUsize::new(0);
+/// static CANCELLED: AtomicUsize = AtomicUsize::new(0);
+/// static INNER: AtomicUsize = AtomicUsize::new(0);
+/// static OUTER: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn main() {
+///     let state = FocusState::new();
+///     let field = ()
+///         .on_key_press(KeyEquivalent::RETURN, || {
+///             SUBMITTED.fetch_add(1, Ordering::SeqCst);
+///             KeyPressResult::Handled
+///         })
+///         .focusable(true)
+///         .focused(FocusId::new(1), state.clone());
+///     let window = ().on_key_press(KeyEquivalent::ESCAPE, || {
+///         CANCELLED.fetch_add(1, Ordering::SeqCst);
+///         KeyPressResult::Handled
+///     });
+///     let handlers: [&dyn KeyPressHandler; 2] = [&field, &window];
+///
+///     // The field does not hold the focus yet.
+///     assert_eq!(
+///         dispatch_key_press(KeyEquivalent::RETURN, &handlers),
+///         KeyPressResult::Ignored
+///     );
+///
+///     state.focus(FocusId::new(1));
+///     assert_eq!(
+///         dispatch_key_press(KeyEquivalent::RETURN, &handlers),
+///         KeyPressResult::Handled
+///     );
+///     assert_eq!(
+///         dispatch_key_press(KeyEquivalent::ESCAPE, &handlers),
+///         KeyPressResult::Handled
+///     );
+///     assert_eq!(
+///         dispatch_key_press('x'.into(), &handlers),
+///         KeyPressResult::Ignored
+///     );
+///     assert_eq!(SUBMITTED.load(Ordering::SeqCst), 1);
+///     assert_eq!(CANCELLED.load(Ordering::SeqCst), 1);
+///
+///     // Keys the outer handler is not bound to reach the inner one.
+///     let chained = ()
+///         .on_key_press('a'.into(), || {
+///             INNER.fetch_add(1, Ordering::SeqCst);
+///             KeyPressResult::Handled
+///         })
+///         .on_key_press('b'.into(), || {
+///             OUTER.fetch_add(1, Ordering::SeqCst);
+///             KeyPressResult::Handled
+///         });
+///     let handlers: [&dyn KeyPressHandler; 1] = [&chained];
+///     assert_eq!(
+///         dispatch_key_press('a'.into(), &handlers),
+///         KeyPressResult::Handled
+///     );
+///     assert_eq!(INNER.load(Ordering::SeqCst), 1);
+///     assert_eq!(OUTER.load(Ordering::SeqCst), 0);
+///
+///     // So do the keys the outer handler ignores.
+///     let passthrough = ()
+///         .on_key_press('a'.into(), || {
+///             INNER.fetch_add(1, Ordering::SeqCst);
+///             KeyPressResult::Handled
+///         })
+///         .on_key_press('a'.into(), || KeyPressResult::Ignored);
+///     let handlers: [&dyn KeyPressHandler; 1] = [&passthrough];
+///     assert_eq!(
+///         dispatch_key_press('a'.into(), &handlers),
+///         KeyPressResult::Handled
+///     );
+///     assert_eq!(INNER.load(Ordering::SeqCst), 2);
+///     assert_eq!(
+///         dispatch_key_press('c'.into(), &handlers),
+///         KeyPressResult::Ignored
+///     );
+/// }
+/// ```
+pub fn dispatch_key_press(key: KeyEquivalent, handlers: &[&dyn KeyPressHandler]) -> KeyPressResult {
+    if handlers
+        .iter()
+        .any(|handler| handler.handle_key_press(key) == KeyPressResult::Handled)
+    {
+        KeyPressResult::Handled
+    } else {
+        KeyPressResult::Ignored
+    }
+}
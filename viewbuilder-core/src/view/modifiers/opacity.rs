@@ -0,0 +1,63 @@
+//! [`OpacityEffect`](crate::view::modifiers::OpacityEffect) definition.
+
+use super::*;
+
+/// A view rendered with a uniform opacity.
+///
+/// The opacity is clamped to `[0, 1]`, from fully transparent to fully opaque.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     View,
+/// };
+///
+/// fn main() {
+///     assert_eq!(().opacity(0.25).value(), 0.25);
+///     assert_eq!(().opacity(1.5).value(), 1.0);
+///     assert_eq!(().opacity(-0.5).value(), 0.0);
+///
+///     let faded = ().compositing_group().opacity(0.5);
+///     assert!(faded.content().is_composited());
+///     assert_eq!(faded.body().value(), 0.5);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpacityEffect<Content> {
+    content: Content,
+    value: f64,
+}
+
+impl<Content> OpacityEffect<Content> {
+    pub(crate) fn new(content: Content, value: f64) -> Self {
+        Self {
+            content,
+            value: value.clamp(0.0, 1.0),
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The opacity, from `0.0` for fully transparent to `1.0` for fully opaque.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<Content> View for OpacityEffect<Content>
+where
+    Content: View,
+{
+    type Body = OpacityEffect<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        OpacityEffect::new(self.content.body(), self.value)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,110 @@
+//! [`Refreshable`](crate::view::modifiers::Refreshable) definition.
+
+use super::*;
+use std::{
+    future::Future,
+    sync::Arc,
+};
+
+/// A view registering an asynchronous action refreshing its content.
+///
+/// Containers supporting pull-to-refresh run the action through
+/// [`Refreshable::trigger_refresh`], which completes once the refresh does.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::{
+///     future::Future,
+///     pin::pin,
+///     sync::{
+///         atomic::{
+///             AtomicUsize,
+///             Ordering,
+///         },
+///         Arc,
+///     },
+///     task::{
+///         Context,
+///         Poll,
+///         Waker,
+///     },
+/// };
+/// use viewbuilder::prelude::*;
+///
+/// fn block_on<Fut: Future>(future: Fut) -> Fut::Output {
+///     let mut future = pin!(future);
+///     let mut context = Context::from_waker(Waker::noop());
+///     loop {
+///         if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+///             return output;
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let (created, completed) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+///     let (on_create, on_complete) = (Arc::clone(&created), Arc::clone(&completed));
+///     let list = ().refreshable(move || {
+///         on_create.fetch_add(1, Ordering::SeqCst);
+///         let on_complete = Arc::clone(&on_complete);
+///         async move {
+///             on_complete.fetch_add(1, Ordering::SeqCst);
+///         }
+///     });
+///
+///     let refresh = list.trigger_refresh();
+///     assert_eq!(completed.load(Ordering::SeqCst), 0);
+///     block_on(refresh);
+///     assert_eq!(created.load(Ordering::SeqCst), 1);
+///     assert_eq!(completed.load(Ordering::SeqCst), 1);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Refreshable<Content, Action> {
+    content: Content,
+    action: Arc<Action>,
+}
+
+impl<Content, Action, Fut> Refreshable<Content, Action>
+where
+    Action: Fn() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    pub(crate) fn new(content: Content, action: Action) -> Self {
+        Self {
+            content,
+            action: Arc::new(action),
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Runs the refresh action, completing when the refresh does.
+    ///
+    /// The action is only invoked when the returned future is first polled.
+    pub async fn trigger_refresh(&self) {
+        (self.action)().await
+    }
+}
+
+impl<Content, Action, Fut> View for Refreshable<Content, Action>
+where
+    Content: View,
+    Action: Fn() -> Fut + SendSync,
+    Fut: Future<Output = ()>,
+{
+    type Body = Refreshable<Content::Body, Action>;
+
+    fn body(&self) -> Self::Body {
+        Refreshable {
+            content: self.content.body(),
+            action: Arc::clone(&self.action),
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,115 @@
+//! [`SafeAreaPadding`](crate::view::modifiers::SafeAreaPadding) definition.
+
+use super::*;
+use crate::{
+    layout::EdgeInsets,
+    view::environment::{
+        EnvironmentKey,
+        EnvironmentValues,
+        ResolveEnvironment,
+    },
+};
+
+/// The environment key of the insets of the safe area, the part of the view not obscured by
+/// system elements.
+#[derive(Debug)]
+pub struct SafeAreaInsets;
+
+impl EnvironmentKey for SafeAreaInsets {
+    type Value = EdgeInsets;
+
+    const DEFAULT: Self::Value = EdgeInsets::ZERO;
+}
+
+/// A view padded by the safe area insets of its environment plus extra insets.
+///
+/// The combined insets are computed and stored by [`SafeAreaPadding::resolve_insets`]. As the
+/// padding absorbs the safe area, the content sees empty safe area insets.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::EdgeInsets,
+///     prelude::*,
+///     view::{
+///         environment::*,
+///         modifiers::SafeAreaInsets,
+///     },
+/// };
+///
+/// fn main() {
+///     let mut environment = EnvironmentValues::new();
+///     environment.set::<SafeAreaInsets>(EdgeInsets::new(20.0, 0.0, 34.0, 0.0));
+///
+///     let mut view = ().safe_area_padding(EdgeInsets::all(8.0));
+///     let insets = view.resolve_insets(&environment);
+///     assert_eq!(insets, EdgeInsets::new(28.0, 8.0, 42.0, 8.0));
+///     assert_eq!(view.insets(), insets);
+///
+///     let content = view.resolve_environment(&environment);
+///     assert_eq!(content.get::<SafeAreaInsets>(), EdgeInsets::ZERO);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SafeAreaPadding<Content> {
+    content: Content,
+    extra: EdgeInsets,
+    insets: EdgeInsets,
+}
+
+impl<Content> SafeAreaPadding<Content> {
+    pub(crate) fn new(content: Content, extra: EdgeInsets) -> Self {
+        Self {
+            content,
+            extra,
+            insets: extra,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The insets added to the safe area insets.
+    pub fn extra(&self) -> EdgeInsets {
+        self.extra
+    }
+
+    /// The combined insets padding the content, as last resolved.
+    pub fn insets(&self) -> EdgeInsets {
+        self.insets
+    }
+
+    /// Combines the safe area insets of the `environment` with the extra insets and stores them.
+    pub fn resolve_insets(&mut self, environment: &EnvironmentValues) -> EdgeInsets {
+        self.insets = environment.get::<SafeAreaInsets>() + self.extra;
+        self.insets
+    }
+}
+
+impl<Content> ResolveEnvironment for SafeAreaPadding<Content> {
+    fn resolve_environment(&self, parent: &EnvironmentValues) -> EnvironmentValues {
+        let mut environment = parent.clone();
+        environment.set::<SafeAreaInsets>(EdgeInsets::ZERO);
+        environment
+    }
+}
+
+impl<Content> View for SafeAreaPadding<Content>
+where
+    Content: View,
+{
+    type Body = SafeAreaPadding<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        SafeAreaPadding {
+            content: self.content.body(),
+            extra: self.extra,
+            insets: self.insets,
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,97 @@
+//! [`ScrollAnchor`](crate::view::modifiers::ScrollAnchor) definition.
+
+use super::*;
+use crate::layout::Rect;
+use std::collections::HashMap;
+
+/// A view registered as a scroll target.
+///
+/// A scroll container can look up the frame of an anchor through a [`ScrollAnchorRegistry`] to
+/// compute the offset bringing it into view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollAnchor<Content> {
+    content: Content,
+    id: String,
+}
+
+impl<Content> ScrollAnchor<Content> {
+    pub(crate) fn new(content: Content, id: String) -> Self {
+        Self { content, id }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The identifier of the anchor.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl<Content> View for ScrollAnchor<Content>
+where
+    Content: View,
+{
+    type Body = ScrollAnchor<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        ScrollAnchor::new(self.content.body(), self.id.clone())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
+
+/// Maps [`ScrollAnchor`] identifiers to their frames.
+///
+/// Registering an identifier again replaces its frame.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::{
+///         Position,
+///         Rect,
+///         Size,
+///     },
+///     prelude::*,
+///     view::modifiers::ScrollAnchorRegistry,
+/// };
+///
+/// fn main() {
+///     let header = ().scroll_anchor("header");
+///     let footer = ().scroll_anchor("footer");
+///     let header_frame = Rect::new(Position::zero(), Size::new(100.0, 20.0));
+///     let footer_frame = Rect::new(Position::new(0.0, 980.0), Size::new(100.0, 20.0));
+///
+///     let mut registry = ScrollAnchorRegistry::new();
+///     registry.register(&header, header_frame);
+///     registry.register(&footer, footer_frame);
+///
+///     assert_eq!(registry.frame("footer"), Some(footer_frame));
+///     assert_eq!(registry.frame("body"), None);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScrollAnchorRegistry {
+    frames: HashMap<String, Rect>,
+}
+
+impl ScrollAnchorRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the `frame` of an anchor.
+    pub fn register<Content>(&mut self, view: &ScrollAnchor<Content>, frame: Rect) {
+        self.frames.insert(view.id().to_owned(), frame);
+    }
+
+    /// The frame registered for an anchor identifier.
+    pub fn frame(&self, id: &str) -> Option<Rect> {
+        self.frames.get(id).copied()
+    }
+}
@@ -0,0 +1,110 @@
+//! [`ScrollPosition`](crate::view::modifiers::ScrollPosition) definitions.
+
+use super::*;
+use std::sync::{
+    Arc,
+    PoisonError,
+    RwLock,
+};
+
+/// A two-way binding to the identifier of the item a scroll container targets.
+///
+/// Clones share the same state, so the container and the code driving it can both hold the
+/// binding.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::modifiers::ScrollPosition,
+/// };
+///
+/// fn main() {
+///     let position = ScrollPosition::new();
+///     let list = ().scroll_position(position.clone());
+///     assert_eq!(list.position().target(), None);
+///
+///     position.scroll_to(42);
+///     assert_eq!(list.position().target(), Some(42));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ScrollPosition<Id> {
+    target: Arc<RwLock<Option<Id>>>,
+}
+
+impl<Id> ScrollPosition<Id> {
+    /// Creates a binding without a target.
+    pub fn new() -> Self {
+        Self {
+            target: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// The identifier of the targeted item.
+    pub fn target(&self) -> Option<Id>
+    where
+        Id: Clone,
+    {
+        self.target
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the targeted item.
+    pub fn set_target(&self, target: Option<Id>) {
+        *self.target.write().unwrap_or_else(PoisonError::into_inner) = target;
+    }
+
+    /// Targets the item identified by `id`.
+    pub fn scroll_to(&self, id: Id) {
+        self.set_target(Some(id));
+    }
+}
+
+impl<Id> Clone for ScrollPosition<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            target: Arc::clone(&self.target),
+        }
+    }
+}
+
+/// A scroll container bound to a [`ScrollPosition`].
+#[derive(Debug, Clone)]
+pub struct WithScrollPosition<Content, Id> {
+    content: Content,
+    position: ScrollPosition<Id>,
+}
+
+impl<Content, Id> WithScrollPosition<Content, Id> {
+    pub(crate) fn new(content: Content, position: ScrollPosition<Id>) -> Self {
+        Self { content, position }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The bound scroll position.
+    pub fn position(&self) -> &ScrollPosition<Id> {
+        &self.position
+    }
+}
+
+impl<Content, Id> View for WithScrollPosition<Content, Id>
+where
+    Content: View,
+    Id: SendSync,
+{
+    type Body = WithScrollPosition<Content::Body, Id>;
+
+    fn body(&self) -> Self::Body {
+        WithScrollPosition::new(self.content.body(), self.position.clone())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,98 @@
+//! [`SensoryFeedback`](crate::view::modifiers::SensoryFeedback) definition.
+
+use super::*;
+
+/// The kinds of sensory feedback a view can request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Effect {
+    /// Indicates that a task completed successfully.
+    Success,
+    /// Indicates that a task produced a warning.
+    Warning,
+    /// Indicates that a task failed.
+    Error,
+    /// Indicates that the selection changed.
+    Selection,
+    /// Indicates a physical collision.
+    Impact,
+}
+
+/// A view firing a sensory [`Effect`] whenever its trigger value changes.
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::modifiers::Effect,
+/// };
+///
+/// fn main() {
+///     let mut view = ().sensory_feedback(0, Effect::Selection);
+///     assert_eq!(view.reevaluate(0), None);
+///     assert_eq!(view.reevaluate(1), Some(Effect::Selection));
+///     assert_eq!(view.reevaluate(1), None);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensoryFeedback<Content, Trigger> {
+    content: Content,
+    trigger: Trigger,
+    effect: Effect,
+}
+
+impl<Content, Trigger> SensoryFeedback<Content, Trigger>
+where
+    Trigger: PartialEq,
+{
+    pub(crate) fn new(content: Content, trigger: Trigger, effect: Effect) -> Self {
+        Self {
+            content,
+            trigger,
+            effect,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The last trigger value seen.
+    pub fn trigger(&self) -> &Trigger {
+        &self.trigger
+    }
+
+    /// The effect fired when the trigger changes.
+    pub fn effect(&self) -> Effect {
+        self.effect
+    }
+
+    /// Re-evaluates the view with a new `trigger` value.
+    ///
+    /// Returns the effect to fire when the value differs from the previous one, which it then
+    /// replaces.
+    pub fn reevaluate(&mut self, trigger: Trigger) -> Option<Effect> {
+        if self.trigger == trigger {
+            return None;
+        }
+
+        self.trigger = trigger;
+        Some(self.effect)
+    }
+}
+
+impl<Content, Trigger> View for SensoryFeedback<Content, Trigger>
+where
+    Content: View,
+    Trigger: PartialEq + Clone + SendSync,
+{
+    type Body = SensoryFeedback<Content::Body, Trigger>;
+
+    fn body(&self) -> Self::Body {
+        SensoryFeedback::new(self.content.body(), self.trigger.clone(), self.effect)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,68 @@
+//! [`TabItem`](crate::view::modifiers::TabItem) definition.
+
+use super::*;
+
+/// The metadata describing the tab of a view in a [`TabView`](crate::view::TabView).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TabLabel {
+    label: String,
+    system_image: Option<String>,
+}
+
+impl TabLabel {
+    /// Creates the metadata of a tab from its `label` and the name of its `system_image`.
+    pub fn new(label: impl Into<String>, system_image: Option<String>) -> Self {
+        Self {
+            label: label.into(),
+            system_image,
+        }
+    }
+
+    /// The text of the tab.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The name of the system image of the tab.
+    pub fn system_image(&self) -> Option<&str> {
+        self.system_image.as_deref()
+    }
+}
+
+/// A view tagged with the metadata of its tab.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabItem<Content> {
+    content: Content,
+    tab_label: TabLabel,
+}
+
+impl<Content> TabItem<Content> {
+    pub(crate) fn new(content: Content, tab_label: TabLabel) -> Self {
+        Self { content, tab_label }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The metadata of the tab.
+    pub fn tab_label(&self) -> &TabLabel {
+        &self.tab_label
+    }
+}
+
+impl<Content> View for TabItem<Content>
+where
+    Content: View,
+{
+    type Body = TabItem<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        TabItem::new(self.content.body(), self.tab_label.clone())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
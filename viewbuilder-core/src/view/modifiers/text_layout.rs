@@ -0,0 +1,92 @@
+//! [`LineLimit`](crate::view::modifiers::LineLimit) and
+//! [`Truncation`](crate::view::modifiers::Truncation) definitions.
+
+use super::*;
+
+/// Where text that does not fit its lines is truncated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TruncationMode {
+    /// Truncates the beginning of the text.
+    Head,
+    /// Truncates the middle of the text.
+    Middle,
+    /// Truncates the end of the text.
+    #[default]
+    Tail,
+}
+
+/// A view limiting the number of lines of the text it contains.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineLimit<Content> {
+    content: Content,
+    limit: Option<usize>,
+}
+
+impl<Content> LineLimit<Content> {
+    pub(crate) fn new(content: Content, limit: Option<usize>) -> Self {
+        Self { content, limit }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The maximum number of lines, `None` when unlimited.
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+impl<Content> View for LineLimit<Content>
+where
+    Content: View,
+{
+    type Body = LineLimit<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        LineLimit::new(self.content.body(), self.limit)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
+
+/// A view choosing where the text it contains is truncated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Truncation<Content> {
+    content: Content,
+    mode: TruncationMode,
+}
+
+impl<Content> Truncation<Content> {
+    pub(crate) fn new(content: Content, mode: TruncationMode) -> Self {
+        Self { content, mode }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// Where the text is truncated.
+    pub fn mode(&self) -> TruncationMode {
+        self.mode
+    }
+}
+
+impl<Content> View for Truncation<Content>
+where
+    Content: View,
+{
+    type Body = Truncation<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        Truncation::new(self.content.body(), self.mode)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
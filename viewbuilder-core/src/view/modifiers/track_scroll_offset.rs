@@ -0,0 +1,100 @@
+//! [`TrackScrollOffset`](crate::view::modifiers::TrackScrollOffset) definition.
+
+use super::*;
+use crate::layout::{
+    Axis,
+    Rect,
+};
+use std::sync::Arc;
+
+/// A view reporting its offset within the scroll container it is laid out in.
+///
+/// Each layout pass feeds the frames of the container and of the view to
+/// [`TrackScrollOffset::measure`], which invokes the action with the distance from the leading
+/// edge of the container to the leading edge of the view along the scroll axis. The offset
+/// decreases as the content scrolls forward, which suits effects like parallax.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::sync::{
+///     Arc,
+///     Mutex,
+/// };
+/// use viewbuilder::{
+///     layout::*,
+///     prelude::*,
+/// };
+///
+/// fn main() {
+///     let offsets = Arc::new(Mutex::new(Vec::new()));
+///     let recorded = Arc::clone(&offsets);
+///     let mut row = ().track_scroll_offset(move |offset| recorded.lock().unwrap().push(offset));
+///
+///     let container = Rect::new(Position::new(0.0, 100.0), Size::new(320.0, 480.0));
+///     let child = Rect::new(Position::new(0.0, 250.0), Size::new(320.0, 44.0));
+///     assert_eq!(row.measure(container, child, Axis::Vertical), 150.0);
+///
+///     let scrolled = Rect::new(Position::new(0.0, 50.0), Size::new(320.0, 44.0));
+///     assert_eq!(row.measure(container, scrolled, Axis::Vertical), -50.0);
+///     assert_eq!(row.offset(), Some(-50.0));
+///
+///     assert_eq!(*offsets.lock().unwrap(), vec![150.0, -50.0]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TrackScrollOffset<Content, Action> {
+    content: Content,
+    action: Arc<Action>,
+    offset: Option<f64>,
+}
+
+impl<Content, Action> TrackScrollOffset<Content, Action>
+where
+    Action: Fn(f64),
+{
+    pub(crate) fn new(content: Content, action: Action) -> Self {
+        Self {
+            content,
+            action: Arc::new(action),
+            offset: None,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The offset computed by the last measurement.
+    pub fn offset(&self) -> Option<f64> {
+        self.offset
+    }
+
+    /// Feeds the frames of the scroll `container` and of the view, the `child`, scrolling along
+    /// the `axis`, invoking the action with the offset of the view which is then returned.
+    pub fn measure(&mut self, container: Rect, child: Rect, axis: Axis) -> f64 {
+        let offset = child.origin().main(axis) - container.origin().main(axis);
+        self.offset = Some(offset);
+        (self.action)(offset);
+        offset
+    }
+}
+
+impl<Content, Action> View for TrackScrollOffset<Content, Action>
+where
+    Content: View,
+    Action: Fn(f64) + SendSync,
+{
+    type Body = TrackScrollOffset<Content::Body, Action>;
+
+    fn body(&self) -> Self::Body {
+        TrackScrollOffset {
+            content: self.content.body(),
+            action: Arc::clone(&self.action),
+            offset: self.offset,
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
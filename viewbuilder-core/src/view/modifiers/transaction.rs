@@ -0,0 +1,127 @@
+//! [`WithTransaction`](crate::view::modifiers::WithTransaction) definition.
+
+use super::*;
+use crate::view::environment::{
+    EnvironmentKey,
+    EnvironmentValues,
+    ResolveEnvironment,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+/// The curve along which a state change is animated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Animation {
+    /// Progresses at a constant pace over the duration.
+    Linear(Duration),
+    /// Starts and ends slowly over the duration.
+    EaseInOut(Duration),
+    /// Follows the motion of a damped spring.
+    Spring {
+        /// The time the spring takes to complete one oscillation.
+        response: Duration,
+        /// The amount of damping, from `0.0` for an undamped spring to `1.0` for a critically
+        /// damped one.
+        damping_fraction: f64,
+    },
+}
+
+/// The context of a state change, propagated to the descendants of the view where it happens.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Transaction {
+    /// The animation applied to the state change, `None` when it is not animated.
+    pub animation: Option<Animation>,
+}
+
+impl Transaction {
+    /// Creates a transaction applying the given `animation`.
+    pub fn new(animation: Option<Animation>) -> Self {
+        Self { animation }
+    }
+}
+
+impl EnvironmentKey for Transaction {
+    type Value = Self;
+
+    const DEFAULT: Self::Value = Self { animation: None };
+}
+
+/// A view adjusting the [`Transaction`] seen by its content.
+///
+/// The transaction inherited from the environment is handed to the closure, whose changes are
+/// visible to every descendant.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use std::time::Duration;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::{
+///         environment::*,
+///         modifiers::*,
+///     },
+/// };
+///
+/// fn main() {
+///     let animation = Animation::EaseInOut(Duration::from_millis(250));
+///     let view = ().transaction(move |transaction| transaction.animation = Some(animation));
+///
+///     let environment = view.resolve_environment(&EnvironmentValues::new());
+///     assert_eq!(environment.get::<Transaction>().animation, Some(animation));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct WithTransaction<Content, Modify> {
+    content: Content,
+    modify: Arc<Modify>,
+}
+
+impl<Content, Modify> WithTransaction<Content, Modify>
+where
+    Modify: Fn(&mut Transaction),
+{
+    pub(crate) fn new(content: Content, modify: Modify) -> Self {
+        Self {
+            content,
+            modify: Arc::new(modify),
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+}
+
+impl<Content, Modify> ResolveEnvironment for WithTransaction<Content, Modify>
+where
+    Modify: Fn(&mut Transaction),
+{
+    fn resolve_environment(&self, parent: &EnvironmentValues) -> EnvironmentValues {
+        let mut transaction = parent.get::<Transaction>();
+        (self.modify)(&mut transaction);
+        let mut environment = parent.clone();
+        environment.set::<Transaction>(transaction);
+        environment
+    }
+}
+
+impl<Content, Modify> View for WithTransaction<Content, Modify>
+where
+    Content: View,
+    Modify: Fn(&mut Transaction) + SendSync,
+{
+    type Body = WithTransaction<Content::Body, Modify>;
+
+    fn body(&self) -> Self::Body {
+        WithTransaction {
+            content: self.content.body(),
+            modify: Arc::clone(&self.modify),
+        }
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,80 @@
+//! [`WithEnvironment`](crate::view::modifiers::WithEnvironment) definition.
+
+use super::*;
+use crate::view::environment::{
+    EnvironmentKey,
+    EnvironmentValues,
+    ResolveEnvironment,
+};
+use std::marker::PhantomData;
+
+/// A view injecting an environment value for its content.
+#[derive(Debug)]
+pub struct WithEnvironment<Content, Key>
+where
+    Key: EnvironmentKey,
+{
+    content: Content,
+    value: Key::Value,
+    key: PhantomData<fn() -> Key>,
+}
+
+impl<Content, Key> WithEnvironment<Content, Key>
+where
+    Key: EnvironmentKey,
+{
+    pub(crate) fn new(content: Content, value: Key::Value) -> Self {
+        Self {
+            content,
+            value,
+            key: PhantomData,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The injected value.
+    pub fn value(&self) -> &Key::Value {
+        &self.value
+    }
+}
+
+impl<Content, Key> Clone for WithEnvironment<Content, Key>
+where
+    Content: Clone,
+    Key: EnvironmentKey,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.content.clone(), self.value.clone())
+    }
+}
+
+impl<Content, Key> ResolveEnvironment for WithEnvironment<Content, Key>
+where
+    Key: EnvironmentKey,
+{
+    fn resolve_environment(&self, parent: &EnvironmentValues) -> EnvironmentValues {
+        let mut environment = parent.clone();
+        environment.set::<Key>(self.value.clone());
+        environment
+    }
+}
+
+impl<Content, Key> View for WithEnvironment<Content, Key>
+where
+    Content: View,
+    Key: EnvironmentKey,
+{
+    type Body = WithEnvironment<Content::Body, Key>;
+
+    fn body(&self) -> Self::Body {
+        WithEnvironment::new(self.content.body(), self.value.clone())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,81 @@
+//! [`WithPreference`](crate::view::modifiers::WithPreference) definition.
+
+use super::*;
+use crate::view::preference::{
+    PreferenceKey,
+    PreferenceValues,
+    ReducePreferences,
+};
+use std::marker::PhantomData;
+
+/// A view setting a preference value for its ancestors.
+#[derive(Debug)]
+pub struct WithPreference<Content, Key>
+where
+    Key: PreferenceKey,
+{
+    content: Content,
+    value: Key::Value,
+    key: PhantomData<fn() -> Key>,
+}
+
+impl<Content, Key> WithPreference<Content, Key>
+where
+    Key: PreferenceKey,
+{
+    pub(crate) fn new(content: Content, value: Key::Value) -> Self {
+        Self {
+            content,
+            value,
+            key: PhantomData,
+        }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The value set.
+    pub fn value(&self) -> &Key::Value {
+        &self.value
+    }
+}
+
+impl<Content, Key> Clone for WithPreference<Content, Key>
+where
+    Content: Clone,
+    Key: PreferenceKey,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.content.clone(), self.value.clone())
+    }
+}
+
+impl<Content, Key> ReducePreferences for WithPreference<Content, Key>
+where
+    Key: PreferenceKey,
+{
+    fn reduce_preferences(&self, content: &PreferenceValues) -> PreferenceValues {
+        let mut preferences = content.clone();
+        preferences.reduce::<Key>(self.value.clone());
+        preferences
+    }
+}
+
+impl<Content, Key> View for WithPreference<Content, Key>
+where
+    Content: View,
+    Key: PreferenceKey,
+{
+    type Body = WithPreference<Content::Body, Key>;
+
+    fn body(&self) -> Self::Body {
+        WithPreference::new(self.content.body(), self.value.clone())
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+        preferences.reduce::<Key>(self.value.clone());
+    }
+}
@@ -0,0 +1,63 @@
+//! [`ZIndex`](crate::view::modifiers::ZIndex) definition.
+
+use super::*;
+
+/// A view rendered in front of or behind its overlapping siblings.
+///
+/// Containers render their subviews by increasing z-index, the layout order of the subviews being
+/// left unchanged, see [`Layout::render_order`](crate::layout::Layout::render_order). Views
+/// default to a z-index of `0.0`.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     prelude::*,
+/// };
+///
+/// fn main() {
+///     let (back, front, middle) = (().z_index(0.0), ().z_index(2.0), ().z_index(1.0));
+///     let z_indices = [front.value(), back.value(), middle.value()];
+///
+///     let layout = ZStackLayout::default();
+///     let subviews = [Size::new(10.0, 10.0); 3];
+///     let bounds = Rect::new(Position::zero(), Size::new(10.0, 10.0));
+///     assert_eq!(layout.place_subviews(bounds, &subviews).len(), 3);
+///     assert_eq!(layout.render_order(&z_indices), vec![1, 2, 0]);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZIndex<Content> {
+    content: Content,
+    value: f64,
+}
+
+impl<Content> ZIndex<Content> {
+    pub(crate) fn new(content: Content, value: f64) -> Self {
+        Self { content, value }
+    }
+
+    /// The wrapped view.
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    /// The z-index, views with a higher index being rendered in front.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl<Content> View for ZIndex<Content>
+where
+    Content: View,
+{
+    type Body = ZIndex<Content::Body>;
+
+    fn body(&self) -> Self::Body {
+        ZIndex::new(self.content.body(), self.value)
+    }
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.content.collect_preferences(preferences);
+    }
+}
@@ -0,0 +1,119 @@
+//! Values flowing up the view hierarchy.
+//!
+//! Descendants set values with the [`preference`](crate::view::ViewExt::preference) modifier and
+//! ancestors read them from the reduced [`PreferenceValues`], collected by walking their content
+//! with [`View::collect_preferences`].
+
+use super::*;
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// A key identifying a value stored in the [`PreferenceValues`].
+///
+/// When several descendants set a value for the same key, the values are combined by
+/// [`PreferenceKey::reduce`] in traversal order.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::preference::*,
+/// };
+///
+/// struct BadgeCount;
+///
+/// impl PreferenceKey for BadgeCount {
+///     type Value = u32;
+///
+///     const DEFAULT: Self::Value = 0;
+///
+///     fn reduce(value: &mut Self::Value, next: Self::Value) {
+///         *value += next;
+///     }
+/// }
+///
+/// fn main() {
+///     let preferences = PreferenceValues::new();
+///     assert_eq!(preferences.get::<BadgeCount>(), 0);
+///
+///     let inner = ().preference::<BadgeCount>(2);
+///     let outer = ().preference::<BadgeCount>(3);
+///     let preferences = outer.reduce_preferences(&inner.reduce_preferences(&preferences));
+///     assert_eq!(preferences.get::<BadgeCount>(), 5);
+/// }
+/// ```
+pub trait PreferenceKey
+where
+    Self: 'static,
+{
+    /// The type of the value identified by the key.
+    type Value: Clone + SendSync;
+
+    /// The value read when none was set.
+    const DEFAULT: Self::Value;
+
+    /// Combines the `next` value set for the key into the accumulated `value`.
+    fn reduce(value: &mut Self::Value, next: Self::Value);
+}
+
+/// The preference values set by the descendants of a view.
+#[derive(Clone, Default)]
+pub struct PreferenceValues {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl PreferenceValues {
+    /// Creates a set of preferences where every key has its default value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of `Key`, or [`PreferenceKey::DEFAULT`] when it was never set.
+    pub fn get<Key>(&self) -> Key::Value
+    where
+        Key: PreferenceKey,
+    {
+        self.values
+            .get(&TypeId::of::<Key>())
+            .and_then(|value| value.downcast_ref::<Key::Value>())
+            .cloned()
+            .unwrap_or(Key::DEFAULT)
+    }
+
+    /// Whether a value was set for `Key`.
+    pub fn contains<Key>(&self) -> bool
+    where
+        Key: PreferenceKey,
+    {
+        self.values.contains_key(&TypeId::of::<Key>())
+    }
+
+    /// Reduces the `value` of `Key` into the current one.
+    pub fn reduce<Key>(&mut self, value: Key::Value)
+    where
+        Key: PreferenceKey,
+    {
+        let mut reduced = self.get::<Key>();
+        Key::reduce(&mut reduced, value);
+        self.values.insert(TypeId::of::<Key>(), Arc::new(reduced));
+    }
+}
+
+impl Debug for PreferenceValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreferenceValues")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+/// Views adjusting the preferences reported by their content.
+pub trait ReducePreferences {
+    /// The preferences reported to ancestors given those reported by the `content`.
+    fn reduce_preferences(&self, content: &PreferenceValues) -> PreferenceValues;
+}
@@ -0,0 +1,168 @@
+//! [`TabView`](crate::view::TabView) definition.
+
+use super::{
+    modifiers::{
+        TabItem,
+        TabLabel,
+    },
+    *,
+};
+
+/// Collections of views tagged with [`tab_item`](crate::view::ViewExt::tab_item).
+pub trait TabItems
+where
+    Self: SendSync,
+{
+    /// Appends the metadata of the tabs of the collection, in order, to `items`.
+    fn collect_tab_items(&self, items: &mut Vec<TabLabel>);
+
+    /// Reduces the preferences reported by the tabs of the collection, in order, into
+    /// `preferences`.
+    fn collect_tab_preferences(&self, preferences: &mut PreferenceValues);
+}
+
+impl<Content> TabItems for TabItem<Content>
+where
+    Content: View,
+{
+    fn collect_tab_items(&self, items: &mut Vec<TabLabel>) {
+        items.push(self.tab_label().clone());
+    }
+
+    fn collect_tab_preferences(&self, preferences: &mut PreferenceValues) {
+        View::collect_preferences(self, preferences);
+    }
+}
+
+impl<Tabs> TabItems for Vec<Tabs>
+where
+    Tabs: TabItems,
+{
+    fn collect_tab_items(&self, items: &mut Vec<TabLabel>) {
+        for tabs in self {
+            tabs.collect_tab_items(items);
+        }
+    }
+
+    fn collect_tab_preferences(&self, preferences: &mut PreferenceValues) {
+        for tabs in self {
+            tabs.collect_tab_preferences(preferences);
+        }
+    }
+}
+
+macro_rules! impl_tab_items_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name),+> TabItems for ($($name,)+)
+        where
+            $($name: TabItems,)+
+        {
+            #[allow(non_snake_case)]
+            fn collect_tab_items(&self, items: &mut Vec<TabLabel>) {
+                let ($($name,)+) = self;
+                $($name.collect_tab_items(items);)+
+            }
+
+            #[allow(non_snake_case)]
+            fn collect_tab_preferences(&self, preferences: &mut PreferenceValues) {
+                let ($($name,)+) = self;
+                $($name.collect_tab_preferences(preferences);)+
+            }
+        }
+    };
+}
+
+impl_tab_items_for_tuple!(A);
+impl_tab_items_for_tuple!(A, B);
+impl_tab_items_for_tuple!(A, B, C);
+impl_tab_items_for_tuple!(A, B, C, D);
+impl_tab_items_for_tuple!(A, B, C, D, E);
+impl_tab_items_for_tuple!(A, B, C, D, E, F);
+impl_tab_items_for_tuple!(A, B, C, D, E, F, G);
+impl_tab_items_for_tuple!(A, B, C, D, E, F, G, H);
+impl_tab_items_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_tab_items_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+
+/// A container presenting one of its tabs at a time.
+///
+/// The tabs are views tagged with [`tab_item`](crate::view::ViewExt::tab_item), whose metadata
+/// the container collects to build its tab bar. Presenting the tabs is left to the rendering
+/// backend. The preferences reported by the tabs reach the container's ancestors, in tab order.
+///
+/// The tabs are only found when [`tab_item`](crate::view::ViewExt::tab_item) is the outermost
+/// modifier of each of them: a modifier applied after it hides the tab from the container, so
+/// modifiers go on the content before it is tagged.
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     prelude::*,
+///     view::{
+///         modifiers::NavigationTitleKey,
+///         preference::PreferenceValues,
+///         TabView,
+///     },
+///     View,
+/// };
+///
+/// fn main() {
+///     let tabs = TabView::new((
+///         ().tab_item("Home", Some("house".to_string())),
+///         ().tab_item("Settings", None),
+///     ));
+///     let items = tabs.items();
+///     assert_eq!(items.len(), 2);
+///     assert_eq!(items[0].label(), "Home");
+///     assert_eq!(items[0].system_image(), Some("house"));
+///     assert_eq!(items[1].label(), "Settings");
+///     assert_eq!(items[1].system_image(), None);
+///
+///     let titled = TabView::new((
+///         ().navigation_title("Inbox").tab_item("Mail", None),
+///         ().opacity(0.5).tab_item("Drafts", None),
+///     ));
+///     assert_eq!(titled.items().len(), 2);
+///
+///     let mut preferences = PreferenceValues::new();
+///     titled.collect_preferences(&mut preferences);
+///     assert_eq!(preferences.get::<NavigationTitleKey>(), "Inbox");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabView<Tabs> {
+    tabs: Tabs,
+}
+
+impl<Tabs> TabView<Tabs>
+where
+    Tabs: TabItems,
+{
+    /// Creates a container presenting the `tabs`.
+    pub fn new(tabs: Tabs) -> Self {
+        Self { tabs }
+    }
+
+    /// The tagged views.
+    pub fn tabs(&self) -> &Tabs {
+        &self.tabs
+    }
+
+    /// The metadata of the tabs, in order.
+    pub fn items(&self) -> Vec<TabLabel> {
+        let mut items = Vec::new();
+        self.tabs.collect_tab_items(&mut items);
+        items
+    }
+}
+
+impl<Tabs> View for TabView<Tabs>
+where
+    Tabs: TabItems,
+{
+    type Body = Unit;
+
+    fn body(&self) -> Self::Body {}
+
+    fn collect_preferences(&self, preferences: &mut PreferenceValues) {
+        self.tabs.collect_tab_preferences(preferences);
+    }
+}
@@ -0,0 +1,78 @@
+//! [`Text`](crate::view::Text) definition.
+
+use super::*;
+use crate::layout::{
+    Measurable,
+    Size,
+};
+
+/// A view displaying a string.
+///
+/// ```
+/// # use viewbuilder_core as viewbuilder;
+/// use viewbuilder::{
+///     layout::*,
+///     prelude::*,
+///     view::{
+///         modifiers::TruncationMode,
+///         Text,
+///     },
+/// };
+///
+/// fn main() {
+///     let text = Text::new("Hello");
+///     assert_eq!(text.content(), "Hello");
+///     assert_eq!(
+///         text.intrinsic_size(Size::new(100.0, 100.0)),
+///         Size::new(40.0, 16.0)
+///     );
+///
+///     let headline = text
+///         .line_limit(Some(2))
+///         .truncation_mode(TruncationMode::Middle);
+///     assert_eq!(headline.mode(), TruncationMode::Middle);
+///     assert_eq!(headline.content().limit(), Some(2));
+///     assert_eq!(headline.content().content().content(), "Hello");
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Text {
+    content: String,
+}
+
+impl Text {
+    /// The estimated advance of a character.
+    const CHARACTER_WIDTH: f64 = 8.0;
+
+    /// The estimated height of a line.
+    const LINE_HEIGHT: f64 = 16.0;
+
+    /// Creates a view displaying `content`.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+        }
+    }
+
+    /// The displayed string.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+impl View for Text {
+    type Body = EmptyView;
+
+    fn body(&self) -> Self::Body {
+        EmptyView
+    }
+}
+
+impl Measurable for Text {
+    /// Estimates a fixed advance per character laid out on a single line, until text shaping is
+    /// provided by a rendering backend.
+    fn intrinsic_size(&self, _proposal: Size) -> Size {
+        let characters = self.content.chars().count() as f64;
+        Size::new(characters * Self::CHARACTER_WIDTH, Self::LINE_HEIGHT)
+    }
+}
@@ -18,6 +18,9 @@
 
 pub mod prelude;
 pub use viewbuilder_core::{
+    color,
+    layout,
+    pipeline,
     transform,
     View,
 };